@@ -0,0 +1,36 @@
+mod classes;
+use bevy::prelude::*;
+use bevy_ui_dsl::*;
+use classes::*;
+
+/// Runs callbacks when buttons are pressed instead of matching on marker components.
+/// Requires the 'plugin' feature.
+fn main() {
+    App::new()
+        .add_plugins(DefaultPlugins.set(ImagePlugin::default_nearest()))
+        .add_plugins(UiDslPlugin)
+        .add_systems(Startup, startup)
+        .run();
+}
+
+fn startup(mut commands: Commands, assets: Res<AssetServer>, mut scale: ResMut<UiScale>) {
+
+    commands.spawn(Camera2d);
+    scale.0 = 2.0;
+
+    let howdy = commands.register_system(say_howdy);
+    root(c_root, &assets, &mut commands, |p| {
+        node((c_half, c_green), p, |p| {
+            text("Closure callback", c_text, c_pixel, p);
+            click_text_button("Hiya", c_button_left, c_pixel, |_: &mut Commands| println!("Hiya button pressed!!!"), p);
+        });
+        node((c_half, c_blue), p, |p| {
+            text("System callback", c_text, c_pixel, p);
+            text_buttoni("Howdy", c_button_right, c_pixel, (on_click(howdy), on_hover(|_: &mut Commands| println!("Howdy button hovered!!!"))), p);
+        });
+    });
+}
+
+fn say_howdy() {
+    println!("Howdy button pressed!!!");
+}
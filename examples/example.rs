@@ -20,17 +20,17 @@ fn main() {
 
 fn startup(mut commands: Commands, assets: Res<AssetServer>, mut scale: ResMut<UiScale>) {
     // Obligatory camera
-    commands.spawn(Camera2dBundle::default());
+    commands.spawn(Camera2d);
     scale.0 = 2.0;
 
     // Spawns ui and gathers entity ids
     let mut hiya = None;
     let mut howdy = None;
     root(c_root, &assets, &mut commands, |p| {
-        // Spawns the root NodeBundle. AssetServer gets propagated.
+        // Spawns the root UiNode. AssetServer gets propagated.
         node((c_half, c_green), p, |p| {
-            // Spawns the left pane as a NodeBundle.
-            text("This is the left pane!", c_text, c_pixel, p); // Spawns a TextBundle.
+            // Spawns the left pane as a UiNode.
+            text("This is the left pane!", c_text, c_pixel, p); // Spawns a UiText.
             text("Do you like it?", c_text, c_pixel, p);
             text_buttoni(
                 "Hiya",
@@ -40,9 +40,9 @@ fn startup(mut commands: Commands, assets: Res<AssetServer>, mut scale: ResMut<U
                 // BackgroundColor(Color::srgb_u8(66, 135, 245).into()),
                 p,
             )
-            .set(&mut hiya); // Spawns a ButtonBundle with a TextBundle child in the middle. Convenience widget.
+            .set(&mut hiya); // Spawns a UiButton with a UiText child in the middle. Convenience widget.
             grid(6, 6, c_grid, p, |p, _row, _col| {
-                // Spawns a NodeBundle container with a NodeBundle for each cell (6x6).
+                // Spawns a UiNode container with a UiNode for each cell (6x6).
                 image(c_inv_slot, p);
             });
             text("Le grid", c_text, c_pixel, p);
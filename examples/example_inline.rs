@@ -21,7 +21,7 @@ fn main() {
 
 fn startup(mut commands: Commands, assets: Res<AssetServer>, mut scale: ResMut<UiScale>) {
 
-    commands.spawn(Camera2dBundle::default());
+    commands.spawn(Camera2d);
     scale.0 = 2.0;
 
     root(c_root, &assets, &mut commands, |p| {
@@ -0,0 +1,111 @@
+use bevy_asset::Handle;
+use bevy_color::Color;
+use bevy_ecs::bundle::Bundle;
+use bevy_text::{Font, FontSmoothing, TextColor, TextFont, TextLayout};
+use bevy_ui::widget::{Button, ImageNode};
+use bevy_ui::{BackgroundColor, BorderColor, BorderRadius, FocusPolicy, Node, ZIndex};
+use bevy_render::view::Visibility;
+
+
+/// Components spawned by [`node`](crate::node), [`root`](crate::root), [`blank`](crate::blank) and [`grid`](crate::grid).
+/// Takes the place of the deprecated `NodeBundle`.
+#[derive(Bundle, Clone, Debug, Default)]
+pub struct UiNode {
+    pub node: Node,
+    pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
+    pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
+    pub visibility: Visibility,
+    pub z_index: ZIndex,
+}
+
+/// Components spawned by [`text`](crate::text), minus the text itself.
+/// Takes the place of the deprecated `TextBundle`.
+#[derive(Bundle, Clone, Debug, Default)]
+pub struct UiText {
+    pub node: Node,
+    pub layout: TextLayout,
+    pub background_color: BackgroundColor,
+    pub focus_policy: FocusPolicy,
+    pub visibility: Visibility,
+    pub z_index: ZIndex,
+}
+
+/// Components spawned by [`button`](crate::button) and its variants.
+/// Takes the place of the deprecated `ButtonBundle`.
+#[derive(Bundle, Clone, Debug)]
+pub struct UiButton {
+    pub button: Button,
+    pub node: Node,
+    pub image: ImageNode,
+    pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
+    pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
+    pub visibility: Visibility,
+    pub z_index: ZIndex,
+}
+
+impl Default for UiButton {
+    fn default() -> Self {
+        Self {
+            button: Button,
+            node: Node::default(),
+            image: ImageNode::default(),
+            background_color: BackgroundColor::default(),
+            border_color: BorderColor::default(),
+            border_radius: BorderRadius::default(),
+            focus_policy: FocusPolicy::Block,
+            visibility: Visibility::default(),
+            z_index: ZIndex::default(),
+        }
+    }
+}
+
+/// Components spawned by [`image`](crate::image) and [`image_pane`](crate::image_pane).
+/// Takes the place of the deprecated `ImageBundle`.
+#[derive(Bundle, Clone, Debug, Default)]
+pub struct UiImage {
+    pub node: Node,
+    pub image: ImageNode,
+    pub background_color: BackgroundColor,
+    pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
+    pub visibility: Visibility,
+    pub z_index: ZIndex,
+}
+
+/// Style of a run of text.
+/// Bevy split this into [`TextFont`] and [`TextColor`], but it is kept whole here so that text classes stay small.
+#[derive(Clone, Debug)]
+pub struct TextStyle {
+    pub font: Handle<Font>,
+    pub font_size: f32,
+    pub font_smoothing: FontSmoothing,
+    pub color: Color,
+}
+
+impl TextStyle {
+    /// Splits this style into the components bevy_text expects.
+    pub fn into_bundle(self) -> (TextFont, TextColor) {
+        let font = TextFont {
+            font: self.font,
+            font_size: self.font_size,
+            font_smoothing: self.font_smoothing,
+        };
+        (font, TextColor(self.color))
+    }
+}
+
+impl Default for TextStyle {
+    fn default() -> Self {
+        let font = TextFont::default();
+        Self {
+            font: font.font,
+            font_size: font.font_size,
+            font_smoothing: font.font_smoothing,
+            color: TextColor::default().0,
+        }
+    }
+}
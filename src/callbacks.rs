@@ -0,0 +1,136 @@
+use bevy_ecs::entity::EntityHashMap;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemId;
+use bevy_ui::Interaction;
+use super::{AssetClass, Disabled, TextStyle, UiButton, UiChildBuilder, buttoni, text_buttoni};
+
+
+/// Something to run in response to an interaction.
+/// Either a closure over [`Commands`], or a one-shot system registered with [`World::register_system`].
+pub enum Callback {
+    Closure(Box<dyn FnMut(&mut Commands) + Send + Sync>),
+    System(SystemId),
+}
+
+impl Callback {
+    /// Runs the callback, or queues its system to run.
+    pub fn run(&mut self, commands: &mut Commands) {
+        match self {
+            Self::Closure(f) => f(commands),
+            Self::System(id) => commands.run_system(*id),
+        }
+    }
+}
+
+impl<F> From<F> for Callback
+where
+    F: FnMut(&mut Commands) + Send + Sync + 'static,
+{
+    fn from(f: F) -> Self {
+        Self::Closure(Box::new(f))
+    }
+}
+
+impl From<SystemId> for Callback {
+    fn from(id: SystemId) -> Self {
+        Self::System(id)
+    }
+}
+
+/// Callback that runs when an entity is pressed.
+#[derive(Component)]
+pub struct OnClick(pub Callback);
+
+/// Callback that runs when an entity starts being hovered.
+#[derive(Component)]
+pub struct OnHover(pub Callback);
+
+/// Creates an [`OnClick`] component.
+/// Handy as the extras of inline widgets, ie: `text_buttoni("Hiya", c_button, c_pixel, on_click(|c| ...), p)`.
+pub fn on_click(callback: impl Into<Callback>) -> OnClick {
+    OnClick(callback.into())
+}
+
+/// Creates an [`OnHover`] component.
+pub fn on_hover(callback: impl Into<Callback>) -> OnHover {
+    OnHover(callback.into())
+}
+
+/// Runs the [`OnClick`] and [`OnHover`] callbacks of entities whose [`Interaction`] changed.
+/// [`OnHover`] only runs when hovering starts, not when a press is released over the entity.
+/// Entities marked [`Disabled`] are skipped.
+#[allow(clippy::type_complexity)]
+pub fn run_callbacks(
+    mut entities: Query<
+        (Entity, &Interaction, Option<&mut OnClick>, Option<&mut OnHover>),
+        (Changed<Interaction>, Without<Disabled>),
+    >,
+    mut removed: RemovedComponents<OnHover>,
+    mut previous: Local<EntityHashMap<Interaction>>,
+    mut commands: Commands,
+) {
+    for entity in removed.read() {
+        previous.remove(&entity);
+    }
+    for (entity, interaction, on_click, on_hover) in &mut entities {
+        if let Some(mut on_click) = on_click {
+            if *interaction == Interaction::Pressed {
+                on_click.0.run(&mut commands);
+            }
+        }
+        if let Some(mut on_hover) = on_hover {
+            let previous = previous.insert(entity, *interaction).unwrap_or(Interaction::None);
+            if *interaction == Interaction::Hovered && previous == Interaction::None {
+                on_hover.0.run(&mut commands);
+            }
+        }
+    }
+}
+
+/// Spawns a [`UiButton`] with children that runs a callback when pressed.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn click_button(
+    class: impl AssetClass<UiButton>,
+    callback: impl Into<Callback>,
+    parent: &mut UiChildBuilder,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    click_buttoni(class, callback, (), parent, children)
+}
+
+/// Spawns a [`UiButton`] with children that runs a callback when pressed.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn click_buttoni(
+    class: impl AssetClass<UiButton>,
+    callback: impl Into<Callback>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    buttoni(class, (on_click(callback), extras), parent, children)
+}
+
+/// Spawns a [`UiButton`] with a single [`UiText`](crate::UiText) as its child that runs a callback when pressed.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn click_text_button(
+    txt: impl Into<String>,
+    class: impl AssetClass<UiButton>,
+    text_style: impl AssetClass<TextStyle>,
+    callback: impl Into<Callback>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    click_text_buttoni(txt, class, text_style, callback, (), parent)
+}
+
+/// Spawns a [`UiButton`] with a single [`UiText`](crate::UiText) as its child that runs a callback when pressed.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn click_text_buttoni(
+    txt: impl Into<String>,
+    class: impl AssetClass<UiButton>,
+    text_style: impl AssetClass<TextStyle>,
+    callback: impl Into<Callback>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    text_buttoni(txt, class, text_style, (on_click(callback), extras), parent)
+}
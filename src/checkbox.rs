@@ -0,0 +1,128 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::{Interaction, JustifyContent};
+use super::{AssetClass, Class, Disabled, UiButton, UiChildBuilder, UiNode};
+
+
+/// Whether a [`checkbox`] or [`toggle`] is checked.
+/// Flipped when the widget is pressed. Can also be set directly to check or uncheck it.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Checked(pub bool);
+
+/// Sent when the user checks or unchecks a [`checkbox`] or [`toggle`].
+#[derive(Event, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct CheckedChanged {
+    pub entity: Entity,
+    pub checked: bool,
+}
+
+/// Styles a [`checkbox`] or [`toggle`] takes on when unchecked and checked.
+#[derive(Component, Clone, Debug)]
+pub struct CheckedStates {
+    pub unchecked: UiButton,
+    pub checked: UiButton,
+}
+
+impl CheckedStates {
+    /// Style that matches the state given.
+    pub fn get(&self, checked: bool) -> &UiButton {
+        if checked { &self.checked } else { &self.unchecked }
+    }
+}
+
+/// Flips [`Checked`] when its entity is pressed, and sends a [`CheckedChanged`] event.
+#[allow(clippy::type_complexity)]
+pub fn toggle_checked(
+    mut entities: Query<(Entity, &Interaction, &mut Checked), (Changed<Interaction>, Without<Disabled>)>,
+    mut events: EventWriter<CheckedChanged>,
+) {
+    for (entity, interaction, mut checked) in &mut entities {
+        if *interaction != Interaction::Pressed { continue }
+        checked.0 = !checked.0;
+        events.send(CheckedChanged { entity, checked: checked.0 });
+    }
+}
+
+/// Re-inserts the style matching [`Checked`] when it changes.
+#[allow(clippy::type_complexity)]
+pub fn apply_checked_states(
+    entities: Query<(Entity, &CheckedStates, &Checked), Or<(Changed<Checked>, Changed<CheckedStates>)>>,
+    mut commands: Commands,
+) {
+    for (entity, states, checked) in &entities {
+        commands.entity(entity).insert(states.get(checked.0).clone());
+    }
+}
+
+/// Spawns a [`UiButton`] that flips between checked and unchecked when pressed.
+/// The checked class is applied on top of the base class, and typically sets a checkmark image.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn checkbox(
+    checked: bool,
+    class: impl AssetClass<UiButton>,
+    checked_class: impl AssetClass<UiButton>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    checkboxi(checked, class, checked_class, (), parent)
+}
+
+/// Spawns a [`UiButton`] that flips between checked and unchecked when pressed.
+/// The checked class is applied on top of the base class, and typically sets a checkmark image.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn checkboxi(
+    checked: bool,
+    class: impl AssetClass<UiButton>,
+    checked_class: impl AssetClass<UiButton>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    let states = checked_states(UiButton::default(), class, checked_class, parent);
+    parent.spawn((states.get(checked).clone(), states, extras)).insert_state(Checked(checked)).id()
+}
+
+/// Spawns a [`UiButton`] track with a [`UiNode`] knob that slides to the end of the track when checked.
+/// The checked class is applied on top of the base class.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn toggle(
+    checked: bool,
+    class: impl AssetClass<UiButton>,
+    checked_class: impl AssetClass<UiButton>,
+    knob_class: impl Class<UiNode>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    togglei(checked, class, checked_class, knob_class, (), parent)
+}
+
+/// Spawns a [`UiButton`] track with a [`UiNode`] knob that slides to the end of the track when checked.
+/// The checked class is applied on top of the base class.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn togglei(
+    checked: bool,
+    class: impl AssetClass<UiButton>,
+    checked_class: impl AssetClass<UiButton>,
+    knob_class: impl Class<UiNode>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    let mut track = UiButton::default();
+    track.node.justify_content = JustifyContent::FlexStart;
+    let mut states = checked_states(track, class, checked_class, parent);
+    states.checked.node.justify_content = JustifyContent::FlexEnd;
+    let mut knob = UiNode::default();
+    knob_class.apply(&mut knob);
+    let mut track = parent.spawn((states.get(checked).clone(), states, extras));
+    track.insert_state(Checked(checked));
+    track.with_children(|p| { p.spawn(knob); }).id()
+}
+
+fn checked_states(
+    base: UiButton,
+    class: impl AssetClass<UiButton>,
+    checked_class: impl AssetClass<UiButton>,
+    parent: &UiChildBuilder,
+) -> CheckedStates {
+    let mut unchecked = base;
+    class.apply(parent.assets(), &mut unchecked);
+    let mut checked = unchecked.clone();
+    checked_class.apply(parent.assets(), &mut checked);
+    CheckedStates { unchecked, checked }
+}
@@ -3,7 +3,7 @@
 
 use bevy_color::Color;
 use bevy_ui::{
-    AlignContent, AlignItems, AlignSelf, BackgroundColor, Direction, Display, FlexDirection,
+    AlignContent, AlignItems, AlignSelf, BackgroundColor, Display, FlexDirection,
     FlexWrap, JustifyContent, Overflow, OverflowAxis, PositionType, UiRect, Val,
 };
 
@@ -28,10 +28,6 @@ pub const ALIGN_CONTENT_STRETCH: AlignContent = AlignContent::Stretch;
 pub const ALIGN_CONTENT_SPACE_BETWEEN: AlignContent = AlignContent::SpaceBetween;
 pub const ALIGN_CONTENT_SPACE_AROUND: AlignContent = AlignContent::SpaceAround;
 
-pub const INHERIT: Direction = Direction::Inherit;
-pub const LEFT_TO_RIGHT: Direction = Direction::LeftToRight;
-pub const RIGHT_TO_LEFT: Direction = Direction::RightToLeft;
-
 pub const FLEX: Display = Display::Flex;
 pub const NONE: Display = Display::None;
 
@@ -127,11 +123,11 @@ pub fn brgba8(r: u8, g: u8, b: u8, a: u8) -> BackgroundColor {
 }
 
 pub fn brgb(r: f32, g: f32, b: f32) -> BackgroundColor {
-    Color::rgb(r, g, b).into()
+    Color::srgb(r, g, b).into()
 }
 
 pub fn brgba(r: f32, g: f32, b: f32, a: f32) -> BackgroundColor {
-    Color::rgba(r, g, b, a).into()
+    Color::srgba(r, g, b, a).into()
 }
 
 pub const fn auto() -> Val {
@@ -0,0 +1,209 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt;
+use std::marker::PhantomData;
+use bevy_asset::AssetServer;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::EntityCommand;
+use bevy_log::{error, warn};
+use super::{AssetClass, Class};
+
+
+type BoxedClass<B> = Box<dyn Fn(&mut B) + Send + Sync>;
+type BoxedAssetClass<B> = Box<dyn Fn(&AssetServer, &mut B) + Send + Sync>;
+
+/// Class stored in a [`ClassRegistry`], which can be applied any number of times.
+pub enum RegisteredClass<B> {
+    Class(BoxedClass<B>),
+    AssetClass(BoxedAssetClass<B>),
+}
+
+impl<B> AssetClass<B> for &RegisteredClass<B> {
+    fn apply(self, assets: &AssetServer, b: &mut B) {
+        match self {
+            RegisteredClass::Class(class) => class(b),
+            RegisteredClass::AssetClass(class) => class(assets, b),
+        }
+    }
+}
+
+/// Named classes, so that widgets can be styled by strings, ie: `node(registry.class("half green"), p, |p| ...)`.
+/// Useful for data-driven layouts, scripting, and restyling from a console with [`Restyle`].
+/// The same name can be registered once for each bundle type, ie: both a `"big"` [`UiNode`](crate::UiNode) class and a `"big"` [`TextStyle`](crate::TextStyle) class.
+#[derive(Resource, Default)]
+pub struct ClassRegistry {
+    classes: HashMap<(TypeId, String), Box<dyn Any + Send + Sync>>,
+}
+
+impl ClassRegistry {
+
+    /// Registers a [`Class`] under a name, replacing any class of the same bundle type registered under that name.
+    pub fn register_class<B: 'static>(
+        &mut self,
+        name: impl Into<String>,
+        class: impl Class<B> + Clone + Send + Sync + 'static
+    ) -> &mut Self {
+        let class = RegisteredClass::Class(Box::new(move |b: &mut B| class.clone().apply(b)));
+        self.classes.insert((TypeId::of::<B>(), name.into()), Box::new(class));
+        self
+    }
+
+    /// Registers an [`AssetClass`] under a name, replacing any class of the same bundle type registered under that name.
+    pub fn register_asset_class<B: 'static>(
+        &mut self,
+        name: impl Into<String>,
+        class: impl AssetClass<B> + Clone + Send + Sync + 'static
+    ) -> &mut Self {
+        let class = RegisteredClass::AssetClass(Box::new(move |assets: &AssetServer, b: &mut B| class.clone().apply(assets, b)));
+        self.classes.insert((TypeId::of::<B>(), name.into()), Box::new(class));
+        self
+    }
+
+    /// Class of the bundle type given registered under a name.
+    pub fn get<B: 'static>(&self, name: &str) -> Option<&RegisteredClass<B>> {
+        self.classes
+            .get(&(TypeId::of::<B>(), name.to_owned()))
+            .and_then(|class| class.downcast_ref())
+    }
+
+    /// Looks up classes by a string of names separated by whitespace, applied in order, ie: `registry.class("half green")`.
+    /// The bundle type is inferred from the widget the class is given to. Names not registered for it are skipped with a warning,
+    /// so use [`ClassRegistry::resolve`] to handle them instead.
+    pub fn class<'a>(&'a self, names: &'a str) -> NamedClass<'a> {
+        NamedClass { registry: self, names }
+    }
+
+    /// Like [`ClassRegistry::class`], but fails with every name that is not registered for the bundle type given.
+    pub fn resolve<'a, B: 'static>(&'a self, names: &'a str) -> Result<NamedClass<'a>, UnknownClasses> {
+        let unknown: Vec<String> = names
+            .split_whitespace()
+            .filter(|name| self.get::<B>(name).is_none())
+            .map(str::to_owned)
+            .collect();
+        match unknown.is_empty() {
+            true => Ok(self.class(names)),
+            false => Err(UnknownClasses { names: unknown, bundle: bundle_name::<B>() }),
+        }
+    }
+}
+
+/// Error resolving names that are not registered in a [`ClassRegistry`], returned by [`ClassRegistry::resolve`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct UnknownClasses {
+    pub names: Vec<String>,
+    /// Type name of the bundle the names were looked up for, ie: `UiNode`.
+    pub bundle: &'static str,
+}
+
+impl fmt::Display for UnknownClasses {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let names: Vec<String> = self.names.iter().map(|name| format!("'{name}'")).collect();
+        write!(f, "unknown {} classes {}", self.bundle, names.join(", "))
+    }
+}
+
+impl Error for UnknownClasses {}
+
+fn bundle_name<B>() -> &'static str {
+    std::any::type_name::<B>().rsplit("::").next().unwrap_or_default()
+}
+
+/// Classes looked up by name with [`ClassRegistry::class`].
+/// As a [`Class`], names registered with [`ClassRegistry::register_asset_class`] are skipped with a warning, as there is no [`AssetServer`] to apply them with.
+#[derive(Copy, Clone)]
+pub struct NamedClass<'a> {
+    registry: &'a ClassRegistry,
+    names: &'a str,
+}
+
+impl<'a> NamedClass<'a> {
+    /// Classes registered for the bundle type given, in order, warning about the names that are not.
+    fn classes<B: 'static>(self) -> impl Iterator<Item = (&'a str, &'a RegisteredClass<B>)> {
+        self.names.split_whitespace().filter_map(move |name| match self.registry.get::<B>(name) {
+            Some(class) => Some((name, class)),
+            None => {
+                warn!("Skipped unknown {} class '{name}'", bundle_name::<B>());
+                None
+            }
+        })
+    }
+}
+
+impl<B: 'static> AssetClass<B> for NamedClass<'_> {
+    fn apply(self, assets: &AssetServer, b: &mut B) {
+        for (_, class) in self.classes::<B>() {
+            class.apply(assets, b);
+        }
+    }
+}
+
+impl<B: 'static> Class<B> for NamedClass<'_> {
+    fn apply(self, b: &mut B) {
+        for (name, class) in self.classes::<B>() {
+            match class {
+                RegisteredClass::Class(class) => class(b),
+                RegisteredClass::AssetClass(_) => {
+                    warn!("Skipped {} asset class '{name}', which needs an AssetServer", bundle_name::<B>());
+                }
+            }
+        }
+    }
+}
+
+/// Command that replaces a bundle of an entity with one styled by the registered classes named, ie:
+/// `commands.entity(button).queue(Restyle::<UiButton>::new("button_left green"))`.
+/// Like widget functions, the bundle starts out as its default before the classes are applied.
+/// Leaves the entity untouched and logs an error if any of the names are not registered.
+pub struct Restyle<B> {
+    names: String,
+    marker: PhantomData<fn() -> B>,
+}
+
+impl<B> Restyle<B> {
+    pub fn new(names: impl Into<String>) -> Self {
+        Self { names: names.into(), marker: PhantomData }
+    }
+}
+
+impl<B: Bundle + Default> EntityCommand for Restyle<B> {
+    fn apply(self, entity: Entity, world: &mut World) {
+        let assets = world.resource::<AssetServer>().clone();
+        let mut bundle = B::default();
+        match world.resource::<ClassRegistry>().resolve::<B>(&self.names) {
+            Ok(class) => AssetClass::apply(class, &assets, &mut bundle),
+            Err(err) => {
+                error!("Could not restyle {entity}: {err}");
+                return;
+            }
+        }
+        if let Ok(mut entity) = world.get_entity_mut(entity) {
+            entity.insert(bundle);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{UiNode, UiText};
+    use super::*;
+
+    fn c_green(_b: &mut UiNode) {}
+
+    #[test]
+    fn resolve_lists_every_unknown_name() {
+        let mut registry = ClassRegistry::default();
+        registry.register_class("green", c_green);
+        assert!(registry.resolve::<UiNode>("green").is_ok());
+        let err = registry.resolve::<UiNode>("half green red").err().unwrap();
+        assert_eq!(err, UnknownClasses { names: vec!["half".into(), "red".into()], bundle: "UiNode" });
+        assert_eq!(err.to_string(), "unknown UiNode classes 'half', 'red'");
+    }
+
+    #[test]
+    fn resolve_is_per_bundle() {
+        let mut registry = ClassRegistry::default();
+        registry.register_class("green", c_green);
+        assert_eq!(registry.resolve::<UiText>("green").err().unwrap().names, vec!["green".to_owned()]);
+    }
+}
@@ -0,0 +1,133 @@
+use std::marker::PhantomData;
+use bevy_asset::AssetServer;
+use super::{AssetClass, Class};
+#[cfg(feature = "plugin")]
+use super::{Theme, ThemeClass};
+
+
+/// Class that is only applied when the condition holds, ie: `(c_slot, when(row == selected, c_highlight))`.
+/// Works with every kind of class, since `Option`s of classes are classes themselves.
+pub fn when<C>(condition: bool, class: C) -> Option<C> {
+    condition.then_some(class)
+}
+
+/// One class or the other depending on a condition, ie: `either(checked, c_checked, c_unchecked)`.
+pub fn either<L, R>(condition: bool, left: L, right: R) -> Either<L, R> {
+    match condition {
+        true => Either::Left(left),
+        false => Either::Right(right),
+    }
+}
+
+/// Class returned by [`either`], which applies one of two classes.
+#[derive(Copy, Clone, Debug)]
+pub enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+impl<L: Class<B>, R: Class<B>, B> Class<B> for Either<L, R> {
+    fn apply(self, b: &mut B) {
+        match self {
+            Self::Left(class) => class.apply(b),
+            Self::Right(class) => class.apply(b),
+        }
+    }
+}
+
+impl<L: AssetClass<B>, R: AssetClass<B>, B> AssetClass<B> for Either<L, R> {
+    fn apply(self, a: &AssetServer, b: &mut B) {
+        match self {
+            Self::Left(class) => class.apply(a, b),
+            Self::Right(class) => class.apply(a, b),
+        }
+    }
+}
+
+#[cfg(feature = "plugin")]
+impl<L: ThemeClass<B>, R: ThemeClass<B>, B> ThemeClass<B> for Either<L, R> {
+    fn apply(self, t: &Theme, a: &AssetServer, b: &mut B) {
+        match self {
+            Self::Left(class) => class.apply(t, a, b),
+            Self::Right(class) => class.apply(t, a, b),
+        }
+    }
+}
+
+/// Class returned by [`Class::map`] and [`AssetClass::map`], which applies a class to a part of a larger value.
+pub struct Map<C, L, B> {
+    class: C,
+    lens: L,
+    marker: PhantomData<fn(&mut B)>,
+}
+
+impl<C, L, B> Map<C, L, B> {
+    pub(crate) fn new(class: C, lens: L) -> Self {
+        Self { class, lens, marker: PhantomData }
+    }
+}
+
+impl<C: Clone, L: Clone, B> Clone for Map<C, L, B> {
+    fn clone(&self) -> Self {
+        Self::new(self.class.clone(), self.lens.clone())
+    }
+}
+
+impl<C, L, B, T> Class<T> for Map<C, L, B>
+where
+    C: Class<B>,
+    L: FnOnce(&mut T) -> &mut B,
+{
+    fn apply(self, t: &mut T) {
+        self.class.apply((self.lens)(t));
+    }
+}
+
+impl<C, L, B, T> AssetClass<T> for Map<C, L, B>
+where
+    C: AssetClass<B>,
+    L: FnOnce(&mut T) -> &mut B,
+{
+    fn apply(self, a: &AssetServer, t: &mut T) {
+        self.class.apply(a, (self.lens)(t));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::Class;
+    use super::{either, when};
+
+    fn push(value: u32) -> impl FnOnce(&mut Vec<u32>) {
+        move |b| b.push(value)
+    }
+
+    #[test]
+    fn when_applies_only_if_the_condition_holds() {
+        let mut b = Vec::new();
+        (push(1), when(false, push(2)), when(true, push(3))).apply(&mut b);
+        assert_eq!(b, [1, 3]);
+    }
+
+    #[test]
+    fn either_picks_one_class() {
+        let mut b = Vec::new();
+        either(true, push(1), push(2)).apply(&mut b);
+        either(false, push(1), push(2)).apply(&mut b);
+        assert_eq!(b, [1, 2]);
+    }
+
+    #[test]
+    fn and_then_applies_after() {
+        let mut b = Vec::new();
+        push(1).and_then(push(2)).and_then(push(3)).apply(&mut b);
+        assert_eq!(b, [1, 2, 3]);
+    }
+
+    #[test]
+    fn map_applies_to_a_part() {
+        let mut b = (Vec::new(), 0);
+        push(1).map(|b: &mut (Vec<u32>, u32)| &mut b.0).apply(&mut b);
+        assert_eq!(b, (vec![1], 0));
+    }
+}
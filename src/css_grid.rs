@@ -0,0 +1,262 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::{AlignItems, Display, GridPlacement, GridTrack, JustifyContent, Val};
+use super::{Class, UiChildBuilder, UiNode};
+
+
+/// Tracks, gaps, spans and named areas of a [`css_grid`].
+/// Tracks are created with [`GridTrack`], ie: `GridTrack::fr(1.0)`, `GridTrack::px(64.0)`, `GridTrack::auto()` or `GridTrack::minmax(..)`.
+#[derive(Clone, Debug, Default)]
+pub struct GridLayout {
+    /// Left empty, the grid has an `auto` row for each line of the [`areas`](Self::areas), or a single one without areas.
+    pub rows: Vec<GridTrack>,
+    /// Left empty, the grid has an `auto` column for each area in the widest line of the [`areas`](Self::areas),
+    /// or a single one without areas.
+    pub columns: Vec<GridTrack>,
+    pub row_gap: Val,
+    pub column_gap: Val,
+    /// Cells that span more than one track. Spans are cut off at the edge of the grid,
+    /// and those overlapping a named area or an earlier span are ignored.
+    pub spans: Vec<GridSpan>,
+    /// Named areas, like CSS `grid-template-areas`. One string per row, with the area of each column separated by whitespace.
+    /// A `.` leaves the cell unnamed. Each area becomes a single cell spanning the rows and columns it covers.
+    pub areas: Vec<String>,
+}
+
+/// Cell of a [`css_grid`] that starts at a row and column, and spans one or more tracks.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub struct GridSpan {
+    pub row: usize,
+    pub col: usize,
+    pub rows: usize,
+    pub columns: usize,
+}
+
+impl GridLayout {
+    /// Row and column of the cell of the named area given, if it is within the grid.
+    /// Handy for matching areas in the callback of a [`css_grid`], ie: `if Some((row, col)) == layout.area("header") { ... }`.
+    pub fn area(&self, name: &str) -> Option<(usize, usize)> {
+        let (rows, columns) = (self.row_tracks().len(), self.column_tracks().len());
+        self.area_spans()
+            .into_iter()
+            .find(|(area, _)| area == name)
+            .map(|(_, span)| (span.row, span.col))
+            .filter(|&(row, col)| row < rows && col < columns)
+    }
+
+    /// Span covering each named area.
+    fn area_spans(&self) -> Vec<(String, GridSpan)> {
+        let mut spans: Vec<(String, GridSpan)> = Vec::new();
+        for (row, line) in self.areas.iter().enumerate() {
+            for (col, name) in line.split_whitespace().enumerate() {
+                if name.chars().all(|c| c == '.') { continue }
+                match spans.iter_mut().find(|(area, _)| area == name) {
+                    Some((_, span)) => {
+                        let last_row = (span.row + span.rows).max(row + 1);
+                        let last_col = (span.col + span.columns).max(col + 1);
+                        span.row = span.row.min(row);
+                        span.col = span.col.min(col);
+                        span.rows = last_row - span.row;
+                        span.columns = last_col - span.col;
+                    }
+                    None => spans.push((name.to_owned(), GridSpan { row, col, rows: 1, columns: 1 })),
+                }
+            }
+        }
+        spans
+    }
+
+    /// Row tracks, including the implicit ones of a layout without rows.
+    fn row_tracks(&self) -> Vec<GridTrack> {
+        match self.rows.is_empty() {
+            true => vec![GridTrack::auto(); self.areas.len().max(1)],
+            false => self.rows.clone(),
+        }
+    }
+
+    /// Column tracks, including the implicit ones of a layout without columns.
+    fn column_tracks(&self) -> Vec<GridTrack> {
+        let widest = self.areas.iter().map(|line| line.split_whitespace().count()).max().unwrap_or_default();
+        match self.columns.is_empty() {
+            true => vec![GridTrack::auto(); widest.max(1)],
+            false => self.columns.clone(),
+        }
+    }
+
+    /// Span of the cell at each row and column, and whether that is where the cell starts.
+    fn cells(&self) -> Vec<Vec<Option<(GridSpan, bool)>>> {
+        let (rows, columns) = (self.row_tracks().len(), self.column_tracks().len());
+        let mut cells = vec![vec![None; columns]; rows];
+        let spans = self.area_spans().into_iter().map(|(_, span)| span).chain(self.spans.iter().copied());
+        for mut span in spans {
+            // Spans are kept within the grid, since cells placed outside of it would add implicit tracks.
+            if span.row >= rows || span.col >= columns { continue }
+            span.rows = span.rows.clamp(1, rows - span.row);
+            span.columns = span.columns.clamp(1, columns - span.col);
+            let overlaps = cells[span.row..span.row + span.rows]
+                .iter()
+                .any(|cells| cells[span.col..span.col + span.columns].iter().any(Option::is_some));
+            if overlaps { continue }
+            for (row, cells) in cells.iter_mut().enumerate().skip(span.row).take(span.rows) {
+                for (col, cell) in cells.iter_mut().enumerate().skip(span.col).take(span.columns) {
+                    *cell = Some((span, row == span.row && col == span.col));
+                }
+            }
+        }
+        cells
+    }
+}
+
+/// Spawns a [`UiNode`] laid out with [`Display::Grid`], composed of [`UiNode`] cells.
+/// Unlike [`grid`](crate::grid), rows and columns can have different sizes, gaps, and cells spanning more than one track.
+/// The callback function argument spawns the contents of those cells, and is called once per cell with the row and column it starts at.
+pub fn css_grid(
+    layout: &GridLayout,
+    class: impl Class<UiNode>,
+    parent: &mut UiChildBuilder,
+    children: impl FnMut(&mut UiChildBuilder, usize, usize)
+) -> Entity {
+    css_gridi(layout, class, (), parent, children)
+}
+
+/// Spawns a [`UiNode`] laid out with [`Display::Grid`], composed of [`UiNode`] cells.
+/// Unlike [`grid`](crate::grid), rows and columns can have different sizes, gaps, and cells spanning more than one track.
+/// The callback function argument spawns the contents of those cells, and is called once per cell with the row and column it starts at.
+pub fn css_gridi(
+    layout: &GridLayout,
+    class: impl Class<UiNode>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder,
+    mut children: impl FnMut(&mut UiChildBuilder, usize, usize)
+) -> Entity {
+    // Spawns container
+    let mut container_bundle = UiNode::default();
+    class.apply(&mut container_bundle);
+    container_bundle.node.display = Display::Grid;
+    container_bundle.node.grid_template_rows = layout.row_tracks().into_iter().map(|track| track.into()).collect();
+    container_bundle.node.grid_template_columns = layout.column_tracks().into_iter().map(|track| track.into()).collect();
+    container_bundle.node.row_gap = layout.row_gap;
+    container_bundle.node.column_gap = layout.column_gap;
+    let mut container = parent.spawn((container_bundle, extras));
+
+    // Spawns cells as children of the container, skipping those covered by a span that starts elsewhere
+    let mut cell_bundle = UiNode::default();
+    cell_bundle.node.justify_content = JustifyContent::Center;
+    cell_bundle.node.align_items = AlignItems::Center;
+    for (row, cells) in layout.cells().into_iter().enumerate() {
+        for (col, cell) in cells.into_iter().enumerate() {
+            let (rows, columns) = match cell {
+                Some((_, false)) => continue,
+                Some((span, true)) => (span.rows, span.columns),
+                None => (1, 1),
+            };
+            let mut cell_bundle = cell_bundle.clone();
+            cell_bundle.node.grid_row = GridPlacement::start_span(row as i16 + 1, rows as u16);
+            cell_bundle.node.grid_column = GridPlacement::start_span(col as i16 + 1, columns as u16);
+            container = container.with_children(|container| {
+                container
+                    .spawn(cell_bundle)
+                    .with_children(|cell| children(cell, row, col));
+            });
+        }
+    }
+    container.id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn span(row: usize, col: usize, rows: usize, columns: usize) -> GridSpan {
+        GridSpan { row, col, rows, columns }
+    }
+
+    fn layout(rows: usize, columns: usize) -> GridLayout {
+        GridLayout {
+            rows: vec![GridTrack::auto(); rows],
+            columns: vec![GridTrack::auto(); columns],
+            ..Default::default()
+        }
+    }
+
+    /// Spans of the cells that start at each row and column.
+    fn starts(layout: &GridLayout) -> Vec<GridSpan> {
+        layout.cells().into_iter().flatten().flatten().filter(|(_, start)| *start).map(|(span, _)| span).collect()
+    }
+
+    #[test]
+    fn area_spans() {
+        let layout = GridLayout {
+            areas: vec!["header header".into(), "side main".into(), "side .".into()],
+            ..Default::default()
+        };
+        assert_eq!(layout.area_spans(), vec![
+            ("header".to_owned(), span(0, 0, 1, 2)),
+            ("side".to_owned(), span(1, 0, 2, 1)),
+            ("main".to_owned(), span(1, 1, 1, 1)),
+        ]);
+        assert_eq!(layout.area("side"), Some((1, 0)));
+        assert_eq!(layout.area("footer"), None);
+    }
+
+    #[test]
+    fn cells_without_spans() {
+        let cells = layout(2, 3).cells();
+        assert_eq!(cells.len(), 2);
+        assert!(cells.iter().all(|row| row.len() == 3 && row.iter().all(Option::is_none)));
+    }
+
+    #[test]
+    fn spans_cover_cells() {
+        let layout = GridLayout { spans: vec![span(0, 1, 2, 2)], ..layout(3, 3) };
+        let cells = layout.cells();
+        assert_eq!(cells[0][1], Some((span(0, 1, 2, 2), true)));
+        assert_eq!(cells[1][2], Some((span(0, 1, 2, 2), false)));
+        assert_eq!(cells[0][0], None);
+        assert_eq!(cells[2][1], None);
+    }
+
+    #[test]
+    fn spans_are_clamped_at_the_edge() {
+        let layout = GridLayout { spans: vec![span(1, 1, 5, 5), span(0, 9, 1, 1), span(0, 0, 0, 0)], ..layout(3, 3) };
+        assert_eq!(starts(&layout), vec![span(0, 0, 1, 1), span(1, 1, 2, 2)]);
+    }
+
+    #[test]
+    fn overlapping_spans_are_ignored() {
+        let layout = GridLayout {
+            spans: vec![span(0, 0, 2, 2), span(1, 1, 2, 2), span(2, 2, 1, 1)],
+            areas: vec![". . .".into(), ". . .".into(), "a a .".into()],
+            ..layout(3, 3)
+        };
+        assert_eq!(starts(&layout), vec![span(0, 0, 2, 2), span(2, 0, 1, 2), span(2, 2, 1, 1)]);
+    }
+
+    #[test]
+    fn areas_take_precedence_over_spans() {
+        let layout = GridLayout {
+            spans: vec![span(0, 1, 2, 1)],
+            areas: vec!["header header".into(), "side main".into()],
+            ..layout(2, 2)
+        };
+        assert_eq!(starts(&layout), vec![span(0, 0, 1, 2), span(1, 0, 1, 1), span(1, 1, 1, 1)]);
+        assert_eq!(layout.area("header"), Some((0, 0)));
+    }
+
+    #[test]
+    fn areas_outside_the_grid() {
+        let layout = GridLayout { areas: vec!["a".into(), "b".into()], ..layout(1, 1) };
+        assert_eq!(layout.area("a"), Some((0, 0)));
+        assert_eq!(layout.area("b"), None);
+    }
+
+    #[test]
+    fn implicit_tracks() {
+        let cells = GridLayout::default().cells();
+        assert_eq!((cells.len(), cells[0].len()), (1, 1));
+
+        let layout = GridLayout { areas: vec!["a a b".into(), "c d e".into()], ..Default::default() };
+        let cells = layout.cells();
+        assert_eq!((cells.len(), cells[0].len()), (2, 3));
+        assert_eq!(starts(&layout).len(), 5);
+    }
+}
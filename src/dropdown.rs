@@ -0,0 +1,170 @@
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonInput;
+use bevy_input::mouse::MouseButton;
+use bevy_ui::{Display, FlexDirection, GlobalZIndex, Interaction, Node, PositionType, Val};
+use bevy_ui::widget::Text;
+use super::{AssetClass, Class, Disabled, TextStyle, UiButton, UiChildBuilder, UiNode, UiText, buttoni, text_buttoni};
+
+
+/// [`GlobalZIndex`] of the option list of an open [`dropdown`], so that it is drawn above other UI.
+pub const DROPDOWN_Z_INDEX: i32 = 1000;
+
+/// State of a [`dropdown`], stored on its outermost node.
+#[derive(Component, Clone, Debug)]
+pub struct Dropdown {
+    pub options: Vec<String>,
+    /// Index of the selected option. Can be set directly to change the selection.
+    pub selected: usize,
+    /// Whether the option list is showing.
+    pub open: bool,
+    /// Button that shows the selected option.
+    pub header: Entity,
+    /// Node that holds the option buttons.
+    pub list: Entity,
+    label: Entity,
+}
+
+/// Option button of a [`dropdown`].
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DropdownOption {
+    pub dropdown: Entity,
+    pub index: usize,
+}
+
+/// Sent when the user picks an option of a [`dropdown`].
+#[derive(Event, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct DropdownChanged {
+    pub entity: Entity,
+    pub selected: usize,
+}
+
+/// Opens dropdowns when their header is pressed, selects options when they are pressed,
+/// and closes open dropdowns when anything else is pressed.
+pub fn select_dropdowns(
+    mouse: Res<ButtonInput<MouseButton>>,
+    mut dropdowns: Query<(Entity, &mut Dropdown), Without<Disabled>>,
+    options: Query<(&DropdownOption, &Interaction), Changed<Interaction>>,
+    interactions: Query<&Interaction>,
+    mut events: EventWriter<DropdownChanged>,
+) {
+    for (option, interaction) in &options {
+        if *interaction != Interaction::Pressed { continue }
+        let Ok((entity, mut dropdown)) = dropdowns.get_mut(option.dropdown) else { continue };
+        dropdown.open = false;
+        if dropdown.selected != option.index {
+            dropdown.selected = option.index;
+            events.send(DropdownChanged { entity, selected: option.index });
+        }
+    }
+    if !mouse.just_pressed(MouseButton::Left) { return }
+    for (_, mut dropdown) in &mut dropdowns {
+        let header_pressed = interactions.get(dropdown.header) == Ok(&Interaction::Pressed);
+        if header_pressed {
+            dropdown.open = !dropdown.open;
+        }
+        else if dropdown.open {
+            dropdown.open = false;
+        }
+    }
+}
+
+/// Shows or hides option lists, and updates the header label of dropdowns that changed.
+pub fn render_dropdowns(
+    dropdowns: Query<&Dropdown, Changed<Dropdown>>,
+    mut lists: Query<&mut Node>,
+    mut labels: Query<&mut Text>,
+) {
+    for dropdown in &dropdowns {
+        if let Ok(mut list) = lists.get_mut(dropdown.list) {
+            list.display = if dropdown.open { Display::Flex } else { Display::None };
+        }
+        if let Ok(mut label) = labels.get_mut(dropdown.label) {
+            let selected = dropdown.options.get(dropdown.selected).cloned().unwrap_or_default();
+            if label.0 != selected {
+                label.0 = selected;
+            }
+        }
+    }
+}
+
+/// Spawns a [`UiNode`] holding a [`UiButton`] that shows the selected option and, when pressed, opens a list of option buttons above other UI.
+/// The list is positioned absolutely below the header, and closes when an option is picked or anything else is pressed.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn dropdown<S: Into<String>>(
+    options: impl IntoIterator<Item = S>,
+    selected: usize,
+    class: impl AssetClass<UiButton>,
+    list_class: impl Class<UiNode>,
+    option_class: impl AssetClass<UiButton> + Clone,
+    text_style: impl AssetClass<TextStyle>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    dropdowni(options, selected, class, list_class, option_class, text_style, (), parent)
+}
+
+/// Spawns a [`UiNode`] holding a [`UiButton`] that shows the selected option and, when pressed, opens a list of option buttons above other UI.
+/// The list is positioned absolutely below the header, and closes when an option is picked or anything else is pressed.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+#[allow(clippy::too_many_arguments)]
+pub fn dropdowni<S: Into<String>>(
+    options: impl IntoIterator<Item = S>,
+    selected: usize,
+    class: impl AssetClass<UiButton>,
+    list_class: impl Class<UiNode>,
+    option_class: impl AssetClass<UiButton> + Clone,
+    text_style: impl AssetClass<TextStyle>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    let options: Vec<String> = options.into_iter().map(Into::into).collect();
+    let mut style = TextStyle::default();
+    text_style.apply(parent.assets(), &mut style);
+    let mut list = UiNode::default();
+    list.node.position_type = PositionType::Absolute;
+    list.node.top = Val::Percent(100.0);
+    list.node.flex_direction = FlexDirection::Column;
+    list_class.apply(&mut list);
+    list.node.display = Display::None;
+
+    let mut container = parent.spawn((UiNode::default(), extras));
+    let dropdown_entity = container.id();
+    let mut header = Entity::PLACEHOLDER;
+    let mut label = Entity::PLACEHOLDER;
+    let mut list_entity = Entity::PLACEHOLDER;
+    container = container.with_children(|p| {
+        header = buttoni(class, (), p, |p| {
+            let selected = options.get(selected).cloned().unwrap_or_default();
+            label = p.spawn((UiText::default(), Text(selected), style.clone().into_bundle())).id();
+        });
+        list_entity = p.spawn((list, GlobalZIndex(DROPDOWN_Z_INDEX))).with_children(|p| {
+            for (index, option) in options.iter().enumerate() {
+                let dropdown = DropdownOption { dropdown: dropdown_entity, index };
+                text_buttoni(option.clone(), option_class.clone(), style.clone(), dropdown, p);
+            }
+        }).id();
+    });
+    let dropdown = Dropdown {
+        options,
+        selected,
+        open: false,
+        header,
+        list: list_entity,
+        label,
+    };
+    container.queue(move |entity: Entity, world: &mut World| dropdown.replace(entity, world));
+    container.id()
+}
+
+impl Dropdown {
+    /// Inserts this dropdown, keeping the selection and open state of the one it replaces when it is rebuilt.
+    fn replace(mut self, entity: Entity, world: &mut World) {
+        let Ok(mut entity) = world.get_entity_mut(entity) else { return };
+        if let Some(old) = entity.get::<Dropdown>() {
+            if old.selected < self.options.len() {
+                self.selected = old.selected;
+            }
+            self.open = old.open;
+        }
+        entity.insert(self);
+    }
+}
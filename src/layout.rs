@@ -0,0 +1,388 @@
+use std::error::Error;
+use std::fmt;
+use bevy_asset::{Asset, AssetEvent, AssetId, AssetLoader, AssetServer, Assets, Handle, LoadContext};
+use bevy_asset::io::Reader;
+use bevy_ecs::prelude::*;
+use bevy_log::error;
+use bevy_reflect::TypePath;
+use serde::Deserialize;
+use super::{
+    AssetClass, ClassRegistry, TextStyle, UiButton, UiChildBuilder, UiClasses, UiImage, UiNode, UiText, UiTree, UnknownClasses,
+    buttoni, gridi, image_panei, imagei, nodei, rebuildi, rooti, text_buttoni, texti,
+};
+
+
+/// Declarative layout loaded from a `.layout.ron` or `.layout.json` file.
+/// Describes a root [`UiNode`] and the widgets within it, styled by the names of classes in a [`ClassRegistry`].
+/// Class fields hold one or more names separated by whitespace, applied in order.
+#[derive(Asset, TypePath, Deserialize, Clone, Debug, Default)]
+pub struct UiLayout {
+    #[serde(default)]
+    pub class: String,
+    #[serde(default)]
+    pub children: Vec<LayoutElement>,
+}
+
+/// Widget within a [`UiLayout`], matching a widget function of the same name.
+#[derive(Deserialize, Clone, Debug)]
+pub enum LayoutElement {
+    Node {
+        #[serde(default)]
+        class: String,
+        #[serde(default)]
+        children: Vec<LayoutElement>,
+    },
+    Text {
+        text: String,
+        #[serde(default)]
+        class: String,
+        #[serde(default)]
+        text_class: String,
+    },
+    Button {
+        #[serde(default)]
+        class: String,
+        #[serde(default)]
+        children: Vec<LayoutElement>,
+    },
+    TextButton {
+        text: String,
+        #[serde(default)]
+        class: String,
+        #[serde(default)]
+        text_class: String,
+    },
+    Image {
+        #[serde(default)]
+        class: String,
+    },
+    ImagePane {
+        #[serde(default)]
+        class: String,
+        #[serde(default)]
+        children: Vec<LayoutElement>,
+    },
+    /// Cells are listed row by row, each holding the children of that cell.
+    /// There must be `rows * columns` of them, or none at all to leave every cell empty.
+    Grid {
+        rows: usize,
+        columns: usize,
+        #[serde(default)]
+        class: String,
+        #[serde(default)]
+        cells: Vec<Vec<LayoutElement>>,
+    },
+}
+
+impl LayoutElement {
+    fn kind(&self) -> &'static str {
+        match self {
+            Self::Node { .. } => "Node",
+            Self::Text { .. } => "Text",
+            Self::Button { .. } => "Button",
+            Self::TextButton { .. } => "TextButton",
+            Self::Image { .. } => "Image",
+            Self::ImagePane { .. } => "ImagePane",
+            Self::Grid { .. } => "Grid",
+        }
+    }
+}
+
+/// Error spawning a [`UiLayout`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum LayoutError {
+    /// Class names are not registered for the bundle the element spawns.
+    UnknownClasses {
+        /// Elements leading to the one with the unknown classes, ie: `root > Node #0 > Text #2`.
+        path: String,
+        source: UnknownClasses,
+    },
+    /// A grid lists a different number of cells than it has rows times columns.
+    CellCount {
+        path: String,
+        expected: usize,
+        found: usize,
+    },
+}
+
+impl fmt::Display for LayoutError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownClasses { path, source } => write!(f, "{path}: {source}"),
+            Self::CellCount { path, expected, found } => write!(f, "{path}: expected {expected} cells, found {found}"),
+        }
+    }
+}
+
+impl Error for LayoutError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::UnknownClasses { source, .. } => Some(source),
+            Self::CellCount { .. } => None,
+        }
+    }
+}
+
+/// Error loading a [`UiLayout`] file.
+#[derive(Debug)]
+pub enum UiLayoutLoaderError {
+    Io(std::io::Error),
+    /// The `.layout.ron` file is malformed. Includes the line and column of the error.
+    Ron(ron::error::SpannedError),
+    /// The `.layout.json` file is malformed. Includes the line and column of the error.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for UiLayoutLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read layout: {err}"),
+            Self::Ron(err) => write!(f, "malformed RON layout: {err}"),
+            Self::Json(err) => write!(f, "malformed JSON layout: {err}"),
+        }
+    }
+}
+
+impl Error for UiLayoutLoaderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Ron(err) => Some(err),
+            Self::Json(err) => Some(err),
+        }
+    }
+}
+
+/// Root spawned from a [`UiLayout`] asset once it loads, and rebuilt whenever the asset is modified.
+/// Set the handle to switch layouts.
+#[derive(Component, Clone, Debug)]
+pub struct LayoutRoot(pub Handle<UiLayout>);
+
+/// Loads [`UiLayout`] assets from `.layout.ron` and `.layout.json` files.
+#[derive(Default)]
+pub struct UiLayoutLoader;
+
+impl AssetLoader for UiLayoutLoader {
+    type Asset = UiLayout;
+    type Settings = ();
+    type Error = UiLayoutLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<UiLayout, UiLayoutLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await.map_err(UiLayoutLoaderError::Io)?;
+        let json = load_context.path().extension().is_some_and(|extension| extension == "json");
+        if json {
+            serde_json::from_slice(&bytes).map_err(UiLayoutLoaderError::Json)
+        }
+        else {
+            ron::de::from_bytes(&bytes).map_err(UiLayoutLoaderError::Ron)
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["layout.ron", "layout.json"]
+    }
+}
+
+impl UiLayout {
+    /// Checks that every class name in the layout is registered, so that it can be spawned.
+    pub fn validate(&self, registry: &ClassRegistry) -> Result<(), LayoutError> {
+        check::<UiNode>(registry, &self.class, "root")?;
+        validate_children(&self.children, registry, "root")
+    }
+}
+
+fn validate_children(children: &[LayoutElement], registry: &ClassRegistry, path: &str) -> Result<(), LayoutError> {
+    for (index, element) in children.iter().enumerate() {
+        let path = format!("{path} > {} #{index}", element.kind());
+        match element {
+            LayoutElement::Node { class, children } => {
+                check::<UiNode>(registry, class, &path)?;
+                validate_children(children, registry, &path)?;
+            }
+            LayoutElement::Text { class, text_class, .. } => {
+                check::<UiText>(registry, class, &path)?;
+                check::<TextStyle>(registry, text_class, &path)?;
+            }
+            LayoutElement::Button { class, children } => {
+                check::<UiButton>(registry, class, &path)?;
+                validate_children(children, registry, &path)?;
+            }
+            LayoutElement::TextButton { class, text_class, .. } => {
+                check::<UiButton>(registry, class, &path)?;
+                check::<TextStyle>(registry, text_class, &path)?;
+            }
+            LayoutElement::Image { class } => check::<UiImage>(registry, class, &path)?,
+            LayoutElement::ImagePane { class, children } => {
+                check::<UiImage>(registry, class, &path)?;
+                validate_children(children, registry, &path)?;
+            }
+            LayoutElement::Grid { rows, columns, class, cells } => {
+                check::<UiNode>(registry, class, &path)?;
+                if !cells.is_empty() && cells.len() != rows * columns {
+                    return Err(LayoutError::CellCount { path, expected: rows * columns, found: cells.len() });
+                }
+                for (cell, children) in cells.iter().enumerate() {
+                    validate_children(children, registry, &format!("{path} > cell #{cell}"))?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn check<B: 'static>(registry: &ClassRegistry, names: &str, path: &str) -> Result<(), LayoutError> {
+    match registry.resolve::<B>(names) {
+        Ok(_) => Ok(()),
+        Err(source) => Err(LayoutError::UnknownClasses { path: path.to_owned(), source }),
+    }
+}
+
+/// [`UiNode`] styled by the registered classes named, for widgets that take a [`Class`](crate::Class) rather than an [`AssetClass`].
+fn node_classes(registry: &ClassRegistry, names: &str, assets: &AssetServer) -> UiNode {
+    let mut node = UiNode::default();
+    AssetClass::apply(registry.class(names), assets, &mut node);
+    node
+}
+
+/// Spawns a [`UiLayout`] as the root.
+pub fn layout_root(
+    layout: &UiLayout,
+    registry: &ClassRegistry,
+    assets: &AssetServer,
+    commands: &mut Commands,
+) -> Result<Entity, LayoutError> {
+    layout_rooti(layout, registry, assets, commands, ())
+}
+
+/// Spawns a [`UiLayout`] as the root.
+pub fn layout_rooti(
+    layout: &UiLayout,
+    registry: &ClassRegistry,
+    assets: &AssetServer,
+    commands: &mut Commands,
+    extras: impl Bundle,
+) -> Result<Entity, LayoutError> {
+    layout.validate(registry)?;
+    let class = node_classes(registry, &layout.class, assets);
+    Ok(rooti(class, assets, commands, (UiClasses::new(&layout.class), extras), |p| spawn_children(&layout.children, registry, p)))
+}
+
+/// Spawns a root for a [`UiLayout`] asset, whose contents are spawned once the asset loads.
+/// When the asset is modified, such as when its file is edited with hot reloading enabled, the root is rebuilt with [`rebuild`].
+/// The root entity is kept along with the extras inserted into it, as are the entities within it that still match the layout.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn layout_asset_root(handle: Handle<UiLayout>, commands: &mut Commands) -> Entity {
+    layout_asset_rooti(handle, commands, ())
+}
+
+/// Spawns a root for a [`UiLayout`] asset, whose contents are spawned once the asset loads.
+/// When the asset is modified, such as when its file is edited with hot reloading enabled, the root is rebuilt with [`rebuild`].
+/// The root entity is kept along with the extras inserted into it, as are the entities within it that still match the layout.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn layout_asset_rooti(handle: Handle<UiLayout>, commands: &mut Commands, extras: impl Bundle) -> Entity {
+    commands.spawn((UiNode::default(), LayoutRoot(handle), extras)).id()
+}
+
+/// Rebuilds an existing root from a [`UiLayout`], reusing the entities that still match it.
+/// Leaves the root untouched if the layout is invalid.
+pub fn rebuild_layout(
+    root: Entity,
+    layout: &UiLayout,
+    registry: &ClassRegistry,
+    assets: &AssetServer,
+    commands: &mut Commands,
+    tree: &UiTree,
+) -> Result<Entity, LayoutError> {
+    layout.validate(registry)?;
+    let class = node_classes(registry, &layout.class, assets);
+    Ok(rebuildi(root, class, assets, commands, tree, UiClasses::new(&layout.class), |p| spawn_children(&layout.children, registry, p)))
+}
+
+/// Builds [`LayoutRoot`]s when they are spawned, their asset loads or is modified, or classes are registered.
+/// Invalid layouts are logged as errors.
+pub fn build_layout_roots(
+    mut events: EventReader<AssetEvent<UiLayout>>,
+    roots: Query<(Entity, Ref<LayoutRoot>)>,
+    layouts: Res<Assets<UiLayout>>,
+    registry: Res<ClassRegistry>,
+    assets: Res<AssetServer>,
+    tree: UiTree,
+    mut commands: Commands,
+) {
+    let changed: Vec<AssetId<UiLayout>> = events
+        .read()
+        .filter_map(|event| match event {
+            AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } => Some(*id),
+            _ => None,
+        })
+        .collect();
+    for (entity, root) in &roots {
+        if !root.is_changed() && !registry.is_changed() && !changed.contains(&root.0.id()) { continue }
+        let Some(layout) = layouts.get(&root.0) else { continue };
+        if let Err(err) = rebuild_layout(entity, layout, &registry, &assets, &mut commands, &tree) {
+            error!("Could not build layout {:?}: {err}", root.0.path());
+        }
+    }
+}
+
+/// Spawns the root [`UiNode`] of a [`UiLayout`] within other widgets.
+pub fn layout(
+    layout: &UiLayout,
+    registry: &ClassRegistry,
+    parent: &mut UiChildBuilder,
+) -> Result<Entity, LayoutError> {
+    layouti(layout, registry, (), parent)
+}
+
+/// Spawns the root [`UiNode`] of a [`UiLayout`] within other widgets.
+pub fn layouti(
+    layout: &UiLayout,
+    registry: &ClassRegistry,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder,
+) -> Result<Entity, LayoutError> {
+    layout.validate(registry)?;
+    let class = node_classes(registry, &layout.class, parent.assets());
+    Ok(nodei(class, (UiClasses::new(&layout.class), extras), parent, |p| spawn_children(&layout.children, registry, p)))
+}
+
+fn spawn_children(children: &[LayoutElement], registry: &ClassRegistry, parent: &mut UiChildBuilder) {
+    for element in children {
+        match element {
+            LayoutElement::Node { class, children } => {
+                let node_class = node_classes(registry, class, parent.assets());
+                nodei(node_class, UiClasses::new(class), parent, |p| spawn_children(children, registry, p));
+            }
+            LayoutElement::Text { text: value, class, text_class } => {
+                let classes = UiClasses::new(format!("{class} {text_class}"));
+                texti(value.clone(), registry.class(class), registry.class(text_class), classes, parent);
+            }
+            LayoutElement::Button { class, children } => {
+                buttoni(registry.class(class), UiClasses::new(class), parent, |p| spawn_children(children, registry, p));
+            }
+            LayoutElement::TextButton { text, class, text_class } => {
+                text_buttoni(text.clone(), registry.class(class), registry.class(text_class), UiClasses::new(class), parent);
+            }
+            LayoutElement::Image { class } => {
+                imagei(registry.class(class), UiClasses::new(class), parent);
+            }
+            LayoutElement::ImagePane { class, children } => {
+                image_panei(registry.class(class), parent, UiClasses::new(class), |p| spawn_children(children, registry, p));
+            }
+            LayoutElement::Grid { rows, columns, class, cells } => {
+                let node_class = node_classes(registry, class, parent.assets());
+                gridi(*rows, *columns, node_class, UiClasses::new(class), parent, |p, row, col| {
+                    if let Some(children) = cells.get(row * columns + col) {
+                        spawn_children(children, registry, p);
+                    }
+                });
+            }
+        }
+    }
+}
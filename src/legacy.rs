@@ -0,0 +1,214 @@
+//! Shims that let classes written against the bundles of Bevy 0.14 style the components spawned by this crate.
+//! The bundles of this module keep the fields of their Bevy 0.14 counterparts, such as `style` and a [`UiImage`] `image`,
+//! so that old classes only need their imports changed. Wrap an old class in [`legacy`] to keep using it while porting:
+//! ```ignore
+//! use bevy_ui_dsl::legacy::{legacy, NodeBundle};
+//!
+//! fn c_green(b: &mut NodeBundle) { ... }
+//! node(legacy(c_green), p, |p| { ... });
+//! ```
+//! Import the bundles by name, since Bevy's prelude still exports deprecated bundles with the same names.
+
+use bevy_asset::{AssetServer, Handle};
+use bevy_color::Color;
+use bevy_image::Image;
+use bevy_render::view::Visibility;
+use bevy_ui::widget::ImageNode;
+use bevy_ui::{BackgroundColor, BorderColor, BorderRadius, FocusPolicy, Node, ZIndex};
+use super::{AssetClass, Class, UiButton, UiImage as UiImageBundle, UiNode, UiText};
+
+
+/// The `NodeBundle` of Bevy 0.14, minus the components computed by Bevy.
+#[derive(Clone, Debug, Default)]
+pub struct NodeBundle {
+    pub style: Node,
+    pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
+    pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
+    pub visibility: Visibility,
+    pub z_index: ZIndex,
+}
+
+/// The `ButtonBundle` of Bevy 0.14, minus the components computed by Bevy.
+#[derive(Clone, Debug, Default)]
+pub struct ButtonBundle {
+    pub style: Node,
+    pub image: UiImage,
+    pub background_color: BackgroundColor,
+    pub border_color: BorderColor,
+    pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
+    pub visibility: Visibility,
+    pub z_index: ZIndex,
+}
+
+/// The `ImageBundle` of Bevy 0.14, minus the components computed by Bevy.
+#[derive(Clone, Debug, Default)]
+pub struct ImageBundle {
+    pub style: Node,
+    pub image: UiImage,
+    pub background_color: BackgroundColor,
+    pub border_radius: BorderRadius,
+    pub focus_policy: FocusPolicy,
+    pub visibility: Visibility,
+    pub z_index: ZIndex,
+}
+
+/// Fields of the `TextBundle` of Bevy 0.14 that still apply to a [`UiText`], under their old names.
+/// Its text and style are given to [`text`](crate::text) and its [`TextStyle`](crate::TextStyle) class instead.
+#[derive(Clone, Debug, Default)]
+pub struct TextBundle {
+    pub style: Node,
+    pub background_color: BackgroundColor,
+    pub focus_policy: FocusPolicy,
+    pub visibility: Visibility,
+    pub z_index: ZIndex,
+}
+
+/// The `UiImage` of Bevy 0.14, which became [`ImageNode`].
+#[derive(Clone, Debug)]
+pub struct UiImage {
+    pub color: Color,
+    pub texture: Handle<Image>,
+    pub flip_x: bool,
+    pub flip_y: bool,
+}
+
+impl UiImage {
+    pub fn new(texture: Handle<Image>) -> Self {
+        Self { texture, ..Default::default() }
+    }
+
+    /// Copies the fields of an [`ImageNode`].
+    fn of(image: &ImageNode) -> Self {
+        Self { color: image.color, texture: image.image.clone(), flip_x: image.flip_x, flip_y: image.flip_y }
+    }
+
+    /// Copies these fields into an [`ImageNode`], keeping the fields it has that this does not.
+    fn write(self, image: &mut ImageNode) {
+        image.color = self.color;
+        image.image = self.texture;
+        image.flip_x = self.flip_x;
+        image.flip_y = self.flip_y;
+    }
+}
+
+impl Default for UiImage {
+    fn default() -> Self {
+        Self::of(&ImageNode::default())
+    }
+}
+
+impl From<Handle<Image>> for UiImage {
+    fn from(texture: Handle<Image>) -> Self {
+        Self::new(texture)
+    }
+}
+
+/// Class written against a Bevy 0.14 bundle. See [`legacy`].
+pub struct Legacy<F>(pub F);
+
+/// Wraps a class that manipulates a [`NodeBundle`], [`ButtonBundle`], [`ImageBundle`] or [`TextBundle`]
+/// so that it can be passed to widgets that spawn [`UiNode`], [`UiButton`], [`UiImage`](crate::UiImage) or [`UiText`].
+pub fn legacy<F>(class: F) -> Legacy<F> {
+    Legacy(class)
+}
+
+impl<F> Class<UiNode> for Legacy<F>
+where
+    F: FnOnce(&mut NodeBundle),
+{
+    fn apply(self, b: &mut UiNode) {
+        let mut bundle = NodeBundle {
+            style: b.node.clone(),
+            background_color: b.background_color,
+            border_color: b.border_color,
+            border_radius: b.border_radius,
+            focus_policy: b.focus_policy,
+            visibility: b.visibility,
+            z_index: b.z_index,
+        };
+        (self.0)(&mut bundle);
+        b.node = bundle.style;
+        b.background_color = bundle.background_color;
+        b.border_color = bundle.border_color;
+        b.border_radius = bundle.border_radius;
+        b.focus_policy = bundle.focus_policy;
+        b.visibility = bundle.visibility;
+        b.z_index = bundle.z_index;
+    }
+}
+
+impl<F> AssetClass<UiButton> for Legacy<F>
+where
+    F: FnOnce(&AssetServer, &mut ButtonBundle),
+{
+    fn apply(self, a: &AssetServer, b: &mut UiButton) {
+        let mut bundle = ButtonBundle {
+            style: b.node.clone(),
+            image: UiImage::of(&b.image),
+            background_color: b.background_color,
+            border_color: b.border_color,
+            border_radius: b.border_radius,
+            focus_policy: b.focus_policy,
+            visibility: b.visibility,
+            z_index: b.z_index,
+        };
+        (self.0)(a, &mut bundle);
+        b.node = bundle.style;
+        bundle.image.write(&mut b.image);
+        b.background_color = bundle.background_color;
+        b.border_color = bundle.border_color;
+        b.border_radius = bundle.border_radius;
+        b.focus_policy = bundle.focus_policy;
+        b.visibility = bundle.visibility;
+        b.z_index = bundle.z_index;
+    }
+}
+
+impl<F> AssetClass<UiImageBundle> for Legacy<F>
+where
+    F: FnOnce(&AssetServer, &mut ImageBundle),
+{
+    fn apply(self, a: &AssetServer, b: &mut UiImageBundle) {
+        let mut bundle = ImageBundle {
+            style: b.node.clone(),
+            image: UiImage::of(&b.image),
+            background_color: b.background_color,
+            border_radius: b.border_radius,
+            focus_policy: b.focus_policy,
+            visibility: b.visibility,
+            z_index: b.z_index,
+        };
+        (self.0)(a, &mut bundle);
+        b.node = bundle.style;
+        bundle.image.write(&mut b.image);
+        b.background_color = bundle.background_color;
+        b.border_radius = bundle.border_radius;
+        b.focus_policy = bundle.focus_policy;
+        b.visibility = bundle.visibility;
+        b.z_index = bundle.z_index;
+    }
+}
+
+impl<F> AssetClass<UiText> for Legacy<F>
+where
+    F: FnOnce(&AssetServer, &mut TextBundle),
+{
+    fn apply(self, a: &AssetServer, b: &mut UiText) {
+        let mut bundle = TextBundle {
+            style: b.node.clone(),
+            background_color: b.background_color,
+            focus_policy: b.focus_policy,
+            visibility: b.visibility,
+            z_index: b.z_index,
+        };
+        (self.0)(a, &mut bundle);
+        b.node = bundle.style;
+        b.background_color = bundle.background_color;
+        b.focus_policy = bundle.focus_policy;
+        b.visibility = bundle.visibility;
+        b.z_index = bundle.z_index;
+    }
+}
@@ -1,48 +1,182 @@
 //! This crate simplifies the process of creating widgets in bevy using a simple extensible DSL.
 
+/// Invokes a macro once for each tuple size from 2 to 16, with the type parameter and index of each field.
+/// Used to implement the class traits for tuples of classes.
+macro_rules! all_tuples {
+    ($m:ident) => {
+        $m!(F1 0, F2 1);
+        $m!(F1 0, F2 1, F3 2);
+        $m!(F1 0, F2 1, F3 2, F4 3);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8, F10 9);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8, F10 9, F11 10);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8, F10 9, F11 10, F12 11);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8, F10 9, F11 10, F12 11, F13 12);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8, F10 9, F11 10, F12 11, F13 12, F14 13);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8, F10 9, F11 10, F12 11, F13 12, F14 13, F15 14);
+        $m!(F1 0, F2 1, F3 2, F4 3, F5 4, F6 5, F7 6, F8 7, F9 8, F10 9, F11 10, F12 11, F13 12, F14 13, F15 14, F16 15);
+    };
+}
+
 #[cfg(feature = "class_helpers")]
 pub mod class_helpers;
+pub mod legacy;
+mod bundles;
+mod class_registry;
+mod combinators;
+mod css_grid;
+mod rebuild;
+#[cfg(feature = "plugin")]
+mod callbacks;
+#[cfg(feature = "plugin")]
+mod checkbox;
+#[cfg(feature = "plugin")]
+mod dropdown;
+#[cfg(feature = "layout")]
+mod layout;
+#[cfg(feature = "plugin")]
+mod modal;
+#[cfg(feature = "plugin")]
+mod plugin;
+#[cfg(feature = "plugin")]
+mod progress_bar;
+#[cfg(feature = "plugin")]
+mod radio_group;
+#[cfg(feature = "plugin")]
+mod scroll_view;
+#[cfg(feature = "plugin")]
+mod slider;
+#[cfg(feature = "plugin")]
+mod states;
+#[cfg(feature = "layout")]
+mod stylesheet;
+#[cfg(feature = "plugin")]
+mod tabs;
+#[cfg(feature = "plugin")]
+mod text_bound;
+#[cfg(feature = "plugin")]
+mod text_input;
+#[cfg(feature = "plugin")]
+mod theme;
+#[cfg(feature = "plugin")]
+mod tooltip;
+#[cfg(feature = "plugin")]
+mod virtual_list;
 mod widgets;
 
+pub use bundles::*;
+pub use class_registry::*;
+pub use combinators::*;
+pub use css_grid::*;
+pub use rebuild::{UiIdentity, UiKey, UiTree, rebuild, rebuildi};
+#[cfg(feature = "plugin")]
+pub use callbacks::*;
+#[cfg(feature = "plugin")]
+pub use checkbox::*;
+#[cfg(feature = "plugin")]
+pub use dropdown::*;
+#[cfg(feature = "layout")]
+pub use layout::*;
+#[cfg(feature = "plugin")]
+pub use modal::*;
+#[cfg(feature = "plugin")]
+pub use plugin::*;
+#[cfg(feature = "plugin")]
+pub use progress_bar::*;
+#[cfg(feature = "plugin")]
+pub use radio_group::*;
+#[cfg(feature = "plugin")]
+pub use scroll_view::*;
+#[cfg(feature = "plugin")]
+pub use slider::*;
+#[cfg(feature = "plugin")]
+pub use states::*;
+#[cfg(feature = "layout")]
+pub use stylesheet::*;
+#[cfg(feature = "plugin")]
+pub use tabs::*;
+#[cfg(feature = "plugin")]
+pub use text_bound::*;
+#[cfg(feature = "plugin")]
+pub use text_input::*;
+#[cfg(feature = "plugin")]
+pub use theme::*;
+#[cfg(feature = "plugin")]
+pub use tooltip::*;
+#[cfg(feature = "plugin")]
+pub use virtual_list::*;
 pub use widgets::*;
+pub use legacy::legacy;
 use bevy_asset::AssetServer;
 use bevy_ecs::bundle::Bundle;
 use bevy_ecs::entity::Entity;
 use bevy_ecs::system::EntityCommands;
-use bevy_hierarchy::{BuildChildren, ChildBuilder};
-use bevy_text::TextStyle;
-use bevy_ui::node_bundles::{ButtonBundle, ImageBundle, NodeBundle, TextBundle};
+use bevy_hierarchy::{BuildChildren, ChildBuild, ChildBuilder};
+use rebuild::{Rebuilder, Reconciler, Snapshot};
+use std::hash::Hash;
 
 
 /// Wrapper for [`ChildBuilder`] that also propogates an [`AssetServer`] for the children that need it.
 pub struct UiChildBuilder<'a, 'b> {
-    builder: &'a mut ChildBuilder<'b>,
+    builder: Builder<'a, 'b>,
     assets: &'a AssetServer,
+    key: Option<UiKey>,
+}
+
+/// Spawns children, or matches them to the existing children of an entity being rebuilt.
+enum Builder<'a, 'b> {
+    Spawn(&'a mut ChildBuilder<'b>),
+    Rebuild(Rebuilder<'a, 'b>),
 }
 
 impl<'a, 'b> UiChildBuilder<'a, 'b> {
 
     /// Create a new [`UiChildBuilder`] for adding to children of a node.
     pub fn new(builder: &'a mut ChildBuilder<'b>, assets: &'a AssetServer) -> Self {
-        Self { builder, assets }
+        Self { builder: Builder::Spawn(builder), assets, key: None }
     }
 
     pub fn spawn<'c>(&'c mut self, bundle: impl Bundle) -> UiEntityCommands<'a, 'c> {
-        let commands: EntityCommands<'c> = self.builder.spawn(bundle);
-        UiEntityCommands {
-            assets: self.assets,
-            commands,
+        let identity = UiIdentity::of(&bundle, self.key.take());
+        match &mut self.builder {
+            Builder::Spawn(builder) => UiEntityCommands {
+                commands: builder.spawn((bundle, identity)),
+                assets: self.assets,
+                snapshot: None,
+                reconciler: None,
+            },
+            Builder::Rebuild(rebuilder) => UiEntityCommands {
+                snapshot: Some(rebuilder.snapshot()),
+                commands: rebuilder.spawn(bundle, identity),
+                assets: self.assets,
+                reconciler: None,
+            },
         }
     }
+
     pub fn assets(&self) -> &AssetServer {
         self.assets
     }
+
+    /// Keys the next widget spawned, so that [`rebuild`] matches it to the child with the same key rather than by position.
+    /// Handy inline, ie: `image(c_slot, p.key(item.id))`.
+    pub fn key(&mut self, key: impl Hash) -> &mut Self {
+        self.key = Some(UiKey::new(key));
+        self
+    }
 }
 
 /// Wrapper for [`EntityCommands`] that also propagates an [`AssetServer`] for the children that need it.
 pub struct UiEntityCommands<'a, 'b> {
     commands: EntityCommands<'b>,
-    assets: &'a AssetServer
+    assets: &'a AssetServer,
+    snapshot: Option<&'a Snapshot>,
+    /// Children matched so far while rebuilding, finished once these commands are dropped.
+    reconciler: Option<Reconciler>,
 }
 
 impl<'a, 'b> UiEntityCommands<'a, 'b> {
@@ -53,21 +187,81 @@ impl<'a, 'b> UiEntityCommands<'a, 'b> {
         self.commands.insert(bundle);
         self
     }
+
+    /// Inserts the state of a widget, such as whether a checkbox is checked, unless the entity already has it.
+    /// Unlike [`insert`](Self::insert), the state of an entity reused by [`rebuild`] is kept.
+    pub fn insert_state(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.commands.insert_if_new(bundle);
+        self
+    }
+
+    #[cfg(feature = "plugin")]
+    pub(crate) fn queue(&mut self, command: impl bevy_ecs::system::EntityCommand) -> &mut Self {
+        self.commands.queue(command);
+        self
+    }
     pub fn with_children(mut self, spawn_children: impl FnOnce(&mut UiChildBuilder)) -> Self {
-        self.commands.with_children(move |builder| {
-            let mut ui_builder = UiChildBuilder {
-                assets: self.assets,
-                builder,
-            };
-            spawn_children(&mut ui_builder);
-        });
+        let Some(snapshot) = self.snapshot else {
+            self.commands.with_children(move |builder| {
+                spawn_children(&mut UiChildBuilder::new(builder, self.assets));
+            });
+            return self;
+        };
+        let parent = self.commands.id();
+        let reconciler = self.reconciler.get_or_insert_with(|| Reconciler::new(parent, snapshot));
+        let mut ui_builder = UiChildBuilder {
+            builder: Builder::Rebuild(Rebuilder::new(self.commands.commands(), snapshot, reconciler)),
+            assets: self.assets,
+            key: None,
+        };
+        spawn_children(&mut ui_builder);
         self
     }
 }
 
-/// Something that can overwrite a value, typically a node bundle.
-pub trait Class<B> {
+impl Drop for UiEntityCommands<'_, '_> {
+    fn drop(&mut self) {
+        if let Some(reconciler) = self.reconciler.take() {
+            reconciler.finish(&mut self.commands.commands());
+        }
+    }
+}
+
+/// Something that can overwrite a value, typically one of the bundles in this crate such as [`UiNode`].
+/// Classes can be combined into tuples, applied from left to right, and boxed, collected into a [`Vec`] or wrapped in an [`Option`]
+/// so that they can be chosen at runtime, ie: `let classes: Vec<Box<dyn Class<UiNode>>> = ...`.
+pub trait Class<B>: ApplyBoxedClass<B> {
     fn apply(self, b: &mut B);
+
+    /// Applies another class after this one, ie: `c_half.and_then(c_green)`.
+    fn and_then<C: Class<B>>(self, next: C) -> (Self, C)
+    where
+        Self: Sized,
+    {
+        (self, next)
+    }
+
+    /// Applies this class to a part of a larger value, so that classes of a component can style any bundle containing it,
+    /// ie: `c_size.map(|b: &mut UiNode| &mut b.node)`, where `c_size` is a class of [`Node`](bevy_ui::Node).
+    fn map<T, L>(self, lens: L) -> Map<Self, L, B>
+    where
+        Self: Sized,
+        L: FnOnce(&mut T) -> &mut B,
+    {
+        Map::new(self, lens)
+    }
+}
+
+/// Applies a boxed [`Class`], so that `Box<dyn Class<B>>` is itself a class. Implemented for every class.
+#[doc(hidden)]
+pub trait ApplyBoxedClass<B> {
+    fn apply_boxed(self: Box<Self>, b: &mut B);
+}
+
+impl<C: Class<B>, B> ApplyBoxedClass<B> for C {
+    fn apply_boxed(self: Box<Self>, b: &mut B) {
+        (*self).apply(b);
+    }
 }
 
 impl<T> Class<T> for () {
@@ -83,61 +277,96 @@ where
     }
 }
 
-impl<F1, F2, B> Class<B> for (F1, F2)
-where
-    F1: Class<B>,
-    F2: Class<B>,
-{
+macro_rules! impl_class_tuple {
+    ($($F:ident $i:tt),*) => {
+        impl<$($F,)* B> Class<B> for ($($F,)*)
+        where
+            $($F: Class<B>,)*
+        {
+            fn apply(self, b: &mut B) {
+                $(self.$i.apply(b);)*
+            }
+        }
+    };
+}
+
+all_tuples!(impl_class_tuple);
+
+impl<B> Class<B> for Box<dyn Class<B> + '_> {
     fn apply(self, b: &mut B) {
-        self.0.apply(b);
-        self.1.apply(b);
+        self.apply_boxed(b);
     }
 }
 
-impl<F1, F2, F3, B> Class<B> for (F1, F2, F3)
-where
-    F1: Class<B>,
-    F2: Class<B>,
-    F3: Class<B>,
-{
+impl<B> Class<B> for Box<dyn Class<B> + Send + Sync + '_> {
     fn apply(self, b: &mut B) {
-        self.0.apply(b);
-        self.1.apply(b);
-        self.2.apply(b);
+        self.apply_boxed(b);
     }
 }
 
-impl<F1, F2, F3, F4, B> Class<B> for (F1, F2, F3, F4)
-where
-    F1: Class<B>,
-    F2: Class<B>,
-    F3: Class<B>,
-    F4: Class<B>,
-{
+impl<C: Class<B>, B> Class<B> for Vec<C> {
     fn apply(self, b: &mut B) {
-        self.0.apply(b);
-        self.1.apply(b);
-        self.2.apply(b);
-        self.3.apply(b);
+        for class in self {
+            class.apply(b);
+        }
     }
 }
 
-impl Class<NodeBundle> for NodeBundle {
-    fn apply(self, b: &mut NodeBundle) {
+impl<C: Class<B>, B> Class<B> for Option<C> {
+    fn apply(self, b: &mut B) {
+        if let Some(class) = self {
+            class.apply(b);
+        }
+    }
+}
+
+impl Class<UiNode> for UiNode {
+    fn apply(self, b: &mut UiNode) {
         *b = self;
     }
 }
 
-impl Class<ImageBundle> for ImageBundle {
-    fn apply(self, b: &mut ImageBundle) {
+impl Class<UiImage> for UiImage {
+    fn apply(self, b: &mut UiImage) {
         *b = self;
     }
 }
 
-/// Something that can overwrite a value, typically a node bundle.
+/// Something that can overwrite a value, typically one of the bundles in this crate such as [`UiNode`].
 /// Depends on an [`AssetServer`], unlike [`Class`].
-pub trait AssetClass<B> {
+/// Like classes, asset classes can be combined into tuples, boxed, collected into a [`Vec`] or wrapped in an [`Option`].
+pub trait AssetClass<B>: ApplyBoxedAssetClass<B> {
     fn apply(self, assets: &AssetServer, b: &mut B);
+
+    /// Applies another asset class after this one, ie: `c_button.and_then(c_button_selected)`.
+    fn and_then<C: AssetClass<B>>(self, next: C) -> (Self, C)
+    where
+        Self: Sized,
+    {
+        (self, next)
+    }
+
+    /// Applies this asset class to a part of a larger value, ie: `c_icon.map(|b: &mut UiButton| &mut b.image)`,
+    /// where `c_icon` is an asset class of [`ImageNode`](bevy_ui::widget::ImageNode).
+    fn map<T, L>(self, lens: L) -> Map<Self, L, B>
+    where
+        Self: Sized,
+        L: FnOnce(&mut T) -> &mut B,
+    {
+        Map::new(self, lens)
+    }
+}
+
+/// Applies a boxed [`AssetClass`], so that `Box<dyn AssetClass<B>>` is itself an asset class. Implemented for every asset class.
+#[doc(hidden)]
+pub trait ApplyBoxedAssetClass<B> {
+    fn apply_boxed(self: Box<Self>, assets: &AssetServer, b: &mut B);
+}
+
+impl<C: AssetClass<B>, B> ApplyBoxedAssetClass<B> for C {
+    fn apply_boxed(self: Box<Self>, assets: &AssetServer, b: &mut B) {
+        (*self).apply(assets, b);
+    }
 }
 
 impl<T> AssetClass<T> for () {
@@ -153,53 +382,57 @@ where
     }
 }
 
-impl<F1, F2, B> AssetClass<B> for (F1, F2)
-where
-    F1: AssetClass<B>,
-    F2: AssetClass<B>,
-{
+macro_rules! impl_asset_class_tuple {
+    ($($F:ident $i:tt),*) => {
+        impl<$($F,)* B> AssetClass<B> for ($($F,)*)
+        where
+            $($F: AssetClass<B>,)*
+        {
+            fn apply(self, a: &AssetServer, b: &mut B) {
+                $(self.$i.apply(a, b);)*
+            }
+        }
+    };
+}
+
+all_tuples!(impl_asset_class_tuple);
+
+impl<B> AssetClass<B> for Box<dyn AssetClass<B> + '_> {
     fn apply(self, a: &AssetServer, b: &mut B) {
-        self.0.apply(a, b);
-        self.1.apply(a, b);
+        self.apply_boxed(a, b);
     }
 }
 
-impl<F1, F2, F3, B> AssetClass<B> for (F1, F2, F3)
-where
-    F1: AssetClass<B>,
-    F2: AssetClass<B>,
-    F3: AssetClass<B>,
-{
+impl<B> AssetClass<B> for Box<dyn AssetClass<B> + Send + Sync + '_> {
     fn apply(self, a: &AssetServer, b: &mut B) {
-        self.0.apply(a, b);
-        self.1.apply(a, b);
-        self.2.apply(a, b);
+        self.apply_boxed(a, b);
     }
 }
 
-impl<F1, F2, F3, F4, B> AssetClass<B> for (F1, F2, F3, F4)
-where
-    F1: AssetClass<B>,
-    F2: AssetClass<B>,
-    F3: AssetClass<B>,
-    F4: AssetClass<B>,
-{
+impl<C: AssetClass<B>, B> AssetClass<B> for Vec<C> {
     fn apply(self, a: &AssetServer, b: &mut B) {
-        self.0.apply(a, b);
-        self.1.apply(a, b);
-        self.2.apply(a, b);
-        self.3.apply(a, b);
+        for class in self {
+            class.apply(a, b);
+        }
     }
 }
 
-impl AssetClass<ButtonBundle> for ButtonBundle {
-    fn apply(self, _a: &AssetServer, b: &mut ButtonBundle) {
+impl<C: AssetClass<B>, B> AssetClass<B> for Option<C> {
+    fn apply(self, a: &AssetServer, b: &mut B) {
+        if let Some(class) = self {
+            class.apply(a, b);
+        }
+    }
+}
+
+impl AssetClass<UiButton> for UiButton {
+    fn apply(self, _a: &AssetServer, b: &mut UiButton) {
         *b = self;
     }
 }
 
-impl AssetClass<TextBundle> for TextBundle {
-    fn apply(self, _a: &AssetServer, b: &mut TextBundle) {
+impl AssetClass<UiText> for UiText {
+    fn apply(self, _a: &AssetServer, b: &mut UiText) {
         *b = self;
     }
 }
@@ -227,3 +460,48 @@ impl EntityWriter for Entity {
         entities.push(self);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::Class;
+
+    fn push(value: u32) -> impl FnOnce(&mut Vec<u32>) {
+        move |b| b.push(value)
+    }
+
+    #[test]
+    fn tuples_apply_in_order() {
+        let mut b = Vec::new();
+        (push(1), push(2), push(3), push(4), push(5), push(6)).apply(&mut b);
+        assert_eq!(b, [1, 2, 3, 4, 5, 6]);
+
+        let mut b = Vec::new();
+        (
+            push(1), push(2), push(3), push(4), push(5), push(6), push(7), push(8),
+            push(9), push(10), push(11), push(12), push(13), push(14), push(15), push(16),
+        ).apply(&mut b);
+        assert_eq!(b, (1..=16).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn vecs_apply_in_order() {
+        let mut b = Vec::new();
+        (1..=3).map(push).collect::<Vec<_>>().apply(&mut b);
+        assert_eq!(b, [1, 2, 3]);
+    }
+
+    #[test]
+    fn boxed_classes_mix_types() {
+        let classes: Vec<Box<dyn Class<Vec<u32>>>> = vec![Box::new(push(1)), Box::new((push(2), push(3))), Box::new(())];
+        let mut b = Vec::new();
+        classes.apply(&mut b);
+        assert_eq!(b, [1, 2, 3]);
+    }
+
+    #[test]
+    fn options_apply_when_some() {
+        let mut b = Vec::new();
+        (Some(push(1)), None::<fn(&mut Vec<u32>)>).apply(&mut b);
+        assert_eq!(b, [1]);
+    }
+}
@@ -0,0 +1,130 @@
+use bevy_asset::AssetServer;
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::{BuildChildren, ChildBuild, DespawnRecursiveExt, HierarchyQueryExt, Parent};
+use bevy_input::ButtonInput;
+use bevy_input::keyboard::KeyCode;
+use bevy_ui::{AlignItems, BackgroundColor, FocusPolicy, GlobalZIndex, Interaction, JustifyContent, PositionType, Val};
+use super::{Class, UiChildBuilder, UiNode};
+
+
+/// Default [`GlobalZIndex`] of a [`modal`]'s backdrop, above dropdown lists.
+pub const MODAL_Z_INDEX: i32 = 2000;
+
+/// State of a [`modal`], stored on its backdrop.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct Modal {
+    /// Dialog body, centered within the backdrop.
+    pub body: Entity,
+    /// Whether hitting Escape closes this modal, if it is the topmost one.
+    pub close_on_escape: bool,
+    /// Whether pressing the backdrop outside the body closes this modal.
+    pub close_on_backdrop: bool,
+}
+
+/// Marks a button that closes the [`modal`] it is in when pressed.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct CloseModal;
+
+/// Sent when a [`modal`] is closed and despawned.
+#[derive(Event, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct ModalClosed {
+    pub entity: Entity,
+}
+
+/// Closes modals when their backdrop or a [`CloseModal`] button within them is pressed, or Escape is hit.
+#[allow(clippy::type_complexity)]
+pub fn close_modals(
+    keys: Res<ButtonInput<KeyCode>>,
+    modals: Query<(Entity, &Modal, &Interaction, &GlobalZIndex)>,
+    close_buttons: Query<(Entity, &Interaction), (With<CloseModal>, Changed<Interaction>)>,
+    parents: Query<&Parent>,
+    mut events: EventWriter<ModalClosed>,
+    mut commands: Commands,
+) {
+    let mut closed: Vec<Entity> = modals
+        .iter()
+        .filter(|(_, modal, interaction, _)| modal.close_on_backdrop && **interaction == Interaction::Pressed)
+        .map(|(entity, ..)| entity)
+        .collect();
+    for (button, interaction) in &close_buttons {
+        if *interaction != Interaction::Pressed { continue }
+        if let Some(modal) = parents.iter_ancestors(button).find(|ancestor| modals.contains(*ancestor)) {
+            closed.push(modal);
+        }
+    }
+    if keys.just_pressed(KeyCode::Escape) {
+        let topmost = modals
+            .iter()
+            .filter(|(_, modal, ..)| modal.close_on_escape)
+            .max_by_key(|(.., z_index)| z_index.0);
+        if let Some((entity, ..)) = topmost {
+            closed.push(entity);
+        }
+    }
+    closed.sort();
+    closed.dedup();
+    for entity in closed {
+        commands.entity(entity).despawn_recursive();
+        events.send(ModalClosed { entity });
+    }
+}
+
+/// Spawns a full-screen backdrop above other UI, with a dialog body centered within it.
+/// The backdrop blocks interaction with the UI beneath it, and the modal closes when the backdrop is pressed,
+/// a [`CloseModal`] button within it is pressed, or Escape is hit.
+/// Like [`root`](crate::root), it has no parent.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn modal(
+    z_index: i32,
+    class: impl Class<UiNode>,
+    body_class: impl Class<UiNode>,
+    assets: &AssetServer,
+    commands: &mut Commands,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    modali(z_index, class, body_class, assets, commands, (), children)
+}
+
+/// Spawns a full-screen backdrop above other UI, with a dialog body centered within it.
+/// The backdrop blocks interaction with the UI beneath it, and the modal closes when the backdrop is pressed,
+/// a [`CloseModal`] button within it is pressed, or Escape is hit.
+/// Like [`root`](crate::root), it has no parent.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn modali(
+    z_index: i32,
+    class: impl Class<UiNode>,
+    body_class: impl Class<UiNode>,
+    assets: &AssetServer,
+    commands: &mut Commands,
+    extras: impl Bundle,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    let mut backdrop = UiNode::default();
+    backdrop.node.position_type = PositionType::Absolute;
+    backdrop.node.width = Val::Percent(100.0);
+    backdrop.node.height = Val::Percent(100.0);
+    backdrop.node.justify_content = JustifyContent::Center;
+    backdrop.node.align_items = AlignItems::Center;
+    backdrop.background_color = BackgroundColor(Color::srgba(0.0, 0.0, 0.0, 0.5));
+    class.apply(&mut backdrop);
+    backdrop.focus_policy = FocusPolicy::Block;
+    let mut body = UiNode::default();
+    body_class.apply(&mut body);
+    body.focus_policy = FocusPolicy::Block;
+
+    let mut backdrop = commands.spawn((backdrop, Interaction::default(), GlobalZIndex(z_index), extras));
+    let mut body_entity = Entity::PLACEHOLDER;
+    backdrop.with_children(|builder| {
+        body_entity = builder
+            .spawn(body)
+            .with_children(|builder| children(&mut UiChildBuilder::new(builder, assets)))
+            .id();
+    });
+    backdrop.insert(Modal {
+        body: body_entity,
+        close_on_escape: true,
+        close_on_backdrop: true,
+    });
+    backdrop.id()
+}
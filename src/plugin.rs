@@ -0,0 +1,83 @@
+use bevy_app::{App, Plugin, Update};
+#[cfg(feature = "layout")]
+use bevy_asset::AssetApp;
+use bevy_ecs::schedule::{IntoSystemConfigs, IntoSystemSetConfigs, SystemSet};
+use super::*;
+
+
+/// Registers the systems that drive the runtime behavior of DSL widgets, such as [`state_button`](crate::state_button).
+/// Widgets that only spawn entities work without it.
+/// Feature flag 'plugin' must be enabled to use this plugin.
+pub struct UiDslPlugin;
+
+impl Plugin for UiDslPlugin {
+    fn build(&self, app: &mut App) {
+        app
+            .add_event::<CheckedChanged>()
+            .add_event::<DropdownChanged>()
+            .add_event::<ModalClosed>()
+            .add_event::<RadioChanged>()
+            .add_event::<SliderChanged>()
+            .add_event::<TabChanged>()
+            .add_event::<TextInputChanged>()
+            .add_event::<TextInputSubmitted>()
+            .init_resource::<ClassRegistry>()
+            .init_resource::<Theme>()
+            .init_resource::<UiFocus>()
+            .configure_sets(Update, (
+                UiDslSystems::Input,
+                UiDslSystems::Callbacks,
+                UiDslSystems::Style,
+            ).chain())
+            .add_systems(Update, (
+                (
+                    toggle_checked,
+                    drag_sliders,
+                    (scroll_on_wheel, drag_scroll_views),
+                    select_dropdowns,
+                    select_tabs,
+                    select_radio_buttons,
+                    (focus_text_inputs, edit_text_inputs).chain(),
+                ).in_set(UiDslSystems::Input),
+                (run_callbacks, close_modals).in_set(UiDslSystems::Callbacks),
+                (
+                    apply_themes.after(apply_button_states).after(apply_checked_states).after(render_tabs).after(render_radio_groups),
+                    apply_button_states,
+                    apply_checked_states,
+                    position_slider_thumbs,
+                    animate_progress_bars,
+                    render_text_inputs,
+                    update_bound_texts,
+                    update_scrollbars,
+                    update_virtual_lists,
+                    render_dropdowns,
+                    render_tabs,
+                    render_radio_groups,
+                    (show_tooltips, position_tooltips).chain(),
+                ).in_set(UiDslSystems::Style),
+            ));
+        #[cfg(feature = "layout")]
+        app
+            .init_asset::<UiLayout>()
+            .init_asset_loader::<UiLayoutLoader>()
+            .init_asset::<StyleSheet>()
+            .init_asset_loader::<StyleSheetLoader>()
+            .init_resource::<StyleSheets>()
+            .add_systems(Update, (
+                build_layout_roots,
+                apply_style_sheets,
+            ).chain().after(apply_themes).in_set(UiDslSystems::Style));
+    }
+}
+
+/// Sets the systems added by [`UiDslPlugin`] belong to, in the order they run.
+/// Useful for ordering your own systems relative to them.
+#[derive(SystemSet, Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum UiDslSystems {
+    /// Systems that update widget state from user input, such as [`Checked`](crate::Checked).
+    Input,
+    /// Systems that run user callbacks, such as [`OnClick`](crate::OnClick).
+    Callbacks,
+    /// Systems that restyle widgets.
+    Style,
+}
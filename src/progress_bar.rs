@@ -0,0 +1,289 @@
+use bevy_ecs::prelude::*;
+use bevy_time::Time;
+use bevy_ui::{AlignItems, FlexDirection, JustifyContent, Node, PositionType, Val};
+use bevy_ui::widget::Text;
+use super::{AssetClass, Class, TextStyle, UiChildBuilder, UiNode, UiText};
+
+
+/// Default rate at which a [`progress_bar`] fill catches up to its [`Progress`], per second.
+pub const PROGRESS_SMOOTHING: f32 = 10.0;
+
+/// Progress shown by a [`progress_bar`], from 0 to 1.
+/// Can be set directly, and the fill animates toward it.
+#[derive(Component, Copy, Clone, PartialEq, Debug, Default)]
+pub struct Progress(pub f32);
+
+/// Direction a [`progress_bar`] fills in.
+#[derive(Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub enum ProgressDirection {
+    #[default]
+    LeftToRight,
+    BottomToTop,
+}
+
+/// State of a [`progress_bar`], stored alongside its [`Progress`].
+#[derive(Component, Clone, Debug)]
+pub struct ProgressBar {
+    /// Fill nodes, one per segment.
+    pub fills: Vec<Entity>,
+    /// Text showing the progress as a percentage, if any.
+    pub label: Option<Entity>,
+    pub direction: ProgressDirection,
+    /// Rate at which the fill catches up to the [`Progress`], per second. Zero snaps it instantly.
+    pub smoothing: f32,
+    shown: f32,
+}
+
+impl ProgressBar {
+    /// Progress the fill currently shows, which lags behind the [`Progress`] while animating.
+    pub fn shown(&self) -> f32 {
+        self.shown
+    }
+
+    /// Fraction of the fill of the segment at the index given.
+    fn segment_fraction(&self, index: usize) -> f32 {
+        let segments = self.fills.len() as f32;
+        (self.shown * segments - index as f32).clamp(0.0, 1.0)
+    }
+}
+
+/// Moves the fill of progress bars toward their [`Progress`], and updates their labels.
+pub fn animate_progress_bars(
+    time: Res<Time>,
+    mut bars: Query<(Ref<Progress>, &mut ProgressBar)>,
+    mut nodes: Query<&mut Node>,
+    mut labels: Query<&mut Text>,
+) {
+    for (progress, mut bar) in &mut bars {
+        let target = progress.0.clamp(0.0, 1.0);
+        if progress.is_changed() {
+            if let Some(mut label) = bar.label.and_then(|label| labels.get_mut(label).ok()) {
+                let text = progress_label(target);
+                if label.0 != text {
+                    label.0 = text;
+                }
+            }
+        }
+        if bar.shown == target { continue }
+        let step = if bar.smoothing > 0.0 { 1.0 - (-bar.smoothing * time.delta_secs()).exp() } else { 1.0 };
+        let shown = bar.shown + (target - bar.shown) * step;
+        bar.shown = if (target - shown).abs() < 0.001 { target } else { shown };
+        for (index, fill) in bar.fills.iter().enumerate() {
+            let Ok(mut node) = nodes.get_mut(*fill) else { continue };
+            set_fill(&mut node, bar.direction, bar.segment_fraction(index));
+        }
+    }
+}
+
+fn progress_label(progress: f32) -> String {
+    format!("{}%", (progress * 100.0).round())
+}
+
+fn set_fill(node: &mut Node, direction: ProgressDirection, fraction: f32) {
+    let fill = Val::Percent(fraction * 100.0);
+    match direction {
+        ProgressDirection::LeftToRight => node.width = fill,
+        ProgressDirection::BottomToTop => node.height = fill,
+    }
+}
+
+/// Spawns a [`UiNode`] with a fill node whose length tracks the [`Progress`] of the bar.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn progress_bar(
+    progress: f32,
+    direction: ProgressDirection,
+    class: impl Class<UiNode>,
+    fill_class: impl Class<UiNode>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    progress_bari(progress, direction, class, fill_class, (), parent)
+}
+
+/// Spawns a [`UiNode`] with a fill node whose length tracks the [`Progress`] of the bar.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn progress_bari(
+    progress: f32,
+    direction: ProgressDirection,
+    class: impl Class<UiNode>,
+    fill_class: impl Class<UiNode>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    spawn_progress_bar(progress, direction, class, None, fill_node(direction, fill_class), None::<()>, extras, parent)
+}
+
+/// Spawns a [`UiNode`] with a fill node whose length tracks the [`Progress`] of the bar,
+/// and text showing the progress as a percentage centered over it.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn labeled_progress_bar(
+    progress: f32,
+    direction: ProgressDirection,
+    class: impl Class<UiNode>,
+    fill_class: impl Class<UiNode>,
+    text_style: impl AssetClass<TextStyle>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    labeled_progress_bari(progress, direction, class, fill_class, text_style, (), parent)
+}
+
+/// Spawns a [`UiNode`] with a fill node whose length tracks the [`Progress`] of the bar,
+/// and text showing the progress as a percentage centered over it.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn labeled_progress_bari(
+    progress: f32,
+    direction: ProgressDirection,
+    class: impl Class<UiNode>,
+    fill_class: impl Class<UiNode>,
+    text_style: impl AssetClass<TextStyle>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    spawn_progress_bar(progress, direction, class, None, fill_node(direction, fill_class), Some(text_style), extras, parent)
+}
+
+/// Spawns a [`UiNode`] split into segments, like the pips of a health bar, that fill one after the other as the [`Progress`] of the bar grows.
+/// Each segment holds its own fill node.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn segmented_progress_bar(
+    progress: f32,
+    segments: usize,
+    direction: ProgressDirection,
+    class: impl Class<UiNode>,
+    segment_class: impl Class<UiNode>,
+    fill_class: impl Class<UiNode>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    segmented_progress_bari(progress, segments, direction, class, segment_class, fill_class, (), parent)
+}
+
+/// Spawns a [`UiNode`] split into segments, like the pips of a health bar, that fill one after the other as the [`Progress`] of the bar grows.
+/// Each segment holds its own fill node.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+#[allow(clippy::too_many_arguments)]
+pub fn segmented_progress_bari(
+    progress: f32,
+    segments: usize,
+    direction: ProgressDirection,
+    class: impl Class<UiNode>,
+    segment_class: impl Class<UiNode>,
+    fill_class: impl Class<UiNode>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    let mut segment = UiNode::default();
+    segment.node.flex_grow = 1.0;
+    segment.node.flex_direction = flex_direction(direction);
+    segment_class.apply(&mut segment);
+    let segments = Some((segments.max(1), segment));
+    spawn_progress_bar(progress, direction, class, segments, fill_node(direction, fill_class), None::<()>, extras, parent)
+}
+
+fn flex_direction(direction: ProgressDirection) -> FlexDirection {
+    match direction {
+        ProgressDirection::LeftToRight => FlexDirection::Row,
+        ProgressDirection::BottomToTop => FlexDirection::ColumnReverse,
+    }
+}
+
+fn fill_node(direction: ProgressDirection, fill_class: impl Class<UiNode>) -> UiNode {
+    let mut fill = UiNode::default();
+    match direction {
+        ProgressDirection::LeftToRight => fill.node.height = Val::Percent(100.0),
+        ProgressDirection::BottomToTop => fill.node.width = Val::Percent(100.0),
+    }
+    fill_class.apply(&mut fill);
+    fill
+}
+
+/// Spawns a progress bar. Without segments, the fill is a direct child of the bar.
+#[allow(clippy::too_many_arguments)]
+fn spawn_progress_bar(
+    progress: f32,
+    direction: ProgressDirection,
+    class: impl Class<UiNode>,
+    segments: Option<(usize, UiNode)>,
+    fill: UiNode,
+    label_style: Option<impl AssetClass<TextStyle>>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    let mut container = UiNode::default();
+    container.node.flex_direction = flex_direction(direction);
+    class.apply(&mut container);
+    let mut bar = ProgressBar {
+        fills: vec![Entity::PLACEHOLDER; segments.as_ref().map_or(1, |(count, _)| *count)],
+        label: None,
+        direction,
+        smoothing: PROGRESS_SMOOTHING,
+        shown: progress.clamp(0.0, 1.0),
+    };
+    let segment_fill = |index: usize, bar: &ProgressBar| {
+        let mut fill = fill.clone();
+        set_fill(&mut fill.node, direction, bar.segment_fraction(index));
+        fill
+    };
+    let label_style = label_style.map(|label_style| {
+        let mut style = TextStyle::default();
+        label_style.apply(parent.assets(), &mut style);
+        style
+    });
+
+    let mut container = parent.spawn((container, Progress(progress), extras));
+    container = container.with_children(|p| {
+        match segments {
+            None => bar.fills[0] = p.spawn(segment_fill(0, &bar)).id(),
+            Some((count, segment)) => for index in 0..count {
+                let fill = segment_fill(index, &bar);
+                p.spawn(segment.clone()).with_children(|p| {
+                    bar.fills[index] = p.spawn(fill).id();
+                });
+            }
+        }
+        let Some(style) = label_style else { return };
+        let mut overlay = UiNode::default();
+        overlay.node.position_type = PositionType::Absolute;
+        overlay.node.width = Val::Percent(100.0);
+        overlay.node.height = Val::Percent(100.0);
+        overlay.node.justify_content = JustifyContent::Center;
+        overlay.node.align_items = AlignItems::Center;
+        p.spawn(overlay).with_children(|p| {
+            let text = Text(progress_label(progress.clamp(0.0, 1.0)));
+            bar.label = Some(p.spawn((UiText::default(), text, style.into_bundle())).id());
+        });
+    });
+    container.insert(bar);
+    container.id()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::entity::Entity;
+    use super::{ProgressBar, ProgressDirection, progress_label};
+
+    fn bar(segments: usize, shown: f32) -> ProgressBar {
+        ProgressBar {
+            fills: vec![Entity::PLACEHOLDER; segments],
+            label: None,
+            direction: ProgressDirection::LeftToRight,
+            smoothing: 0.0,
+            shown,
+        }
+    }
+
+    #[test]
+    fn segments_fill_one_after_the_other() {
+        let bar = bar(4, 0.6);
+        let fractions: Vec<f32> = (0..4).map(|index| bar.segment_fraction(index)).collect();
+        assert_eq!(fractions[0], 1.0);
+        assert_eq!(fractions[1], 1.0);
+        assert!((fractions[2] - 0.4).abs() < 1e-5);
+        assert_eq!(fractions[3], 0.0);
+    }
+
+    #[test]
+    fn labels_round_to_whole_percents() {
+        assert_eq!(progress_label(0.0), "0%");
+        assert_eq!(progress_label(0.426), "43%");
+        assert_eq!(progress_label(1.0), "100%");
+    }
+}
@@ -0,0 +1,129 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::Interaction;
+use super::{AssetClass, Class, Disabled, TextStyle, UiButton, UiChildBuilder, UiNode, text};
+
+
+/// Index of the selected button of a [`radio_group`].
+/// Set when a button is pressed. Can also be set directly to change the selection.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct SelectedIndex(pub usize);
+
+/// Buttons of a [`radio_group`], stored on its outermost node.
+#[derive(Component, Clone, Debug)]
+pub struct RadioGroup {
+    pub buttons: Vec<Entity>,
+}
+
+/// Button of a [`radio_group`].
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct RadioButton {
+    pub group: Entity,
+    pub index: usize,
+}
+
+/// Styles a radio button takes on when unselected and selected.
+#[derive(Component, Clone, Debug)]
+pub struct RadioButtonStates {
+    pub unselected: UiButton,
+    pub selected: UiButton,
+}
+
+impl RadioButtonStates {
+    /// Style that matches the state given.
+    pub fn get(&self, selected: bool) -> &UiButton {
+        if selected { &self.selected } else { &self.unselected }
+    }
+}
+
+/// Sent when the user selects another button of a [`radio_group`].
+#[derive(Event, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct RadioChanged {
+    pub entity: Entity,
+    pub selected: usize,
+}
+
+/// Sets [`SelectedIndex`] when a radio button is pressed, and sends a [`RadioChanged`] event.
+#[allow(clippy::type_complexity)]
+pub fn select_radio_buttons(
+    buttons: Query<(&RadioButton, &Interaction), (Changed<Interaction>, Without<Disabled>)>,
+    mut groups: Query<&mut SelectedIndex, Without<Disabled>>,
+    mut events: EventWriter<RadioChanged>,
+) {
+    for (button, interaction) in &buttons {
+        if *interaction != Interaction::Pressed { continue }
+        let Ok(mut selected) = groups.get_mut(button.group) else { continue };
+        if selected.set_if_neq(SelectedIndex(button.index)) {
+            events.send(RadioChanged { entity: button.group, selected: button.index });
+        }
+    }
+}
+
+/// Restyles the buttons of radio groups whose [`SelectedIndex`] changed.
+pub fn render_radio_groups(
+    groups: Query<(&RadioGroup, &SelectedIndex), Changed<SelectedIndex>>,
+    buttons: Query<&RadioButtonStates>,
+    mut commands: Commands,
+) {
+    for (group, selected) in &groups {
+        for (index, button) in group.buttons.iter().enumerate() {
+            let Ok(states) = buttons.get(*button) else { continue };
+            commands.entity(*button).insert(states.get(index == selected.0).clone());
+        }
+    }
+}
+
+/// Spawns a [`UiNode`] with a button for each label, of which only one is selected at a time.
+/// The selected class is applied on top of the button class.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn radio_group<S: Into<String>>(
+    labels: impl IntoIterator<Item = S>,
+    selected: usize,
+    class: impl Class<UiNode>,
+    button_class: impl AssetClass<UiButton>,
+    selected_class: impl AssetClass<UiButton>,
+    text_style: impl AssetClass<TextStyle>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    radio_groupi(labels, selected, class, button_class, selected_class, text_style, (), parent)
+}
+
+/// Spawns a [`UiNode`] with a button for each label, of which only one is selected at a time.
+/// The selected class is applied on top of the button class.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+#[allow(clippy::too_many_arguments)]
+pub fn radio_groupi<S: Into<String>>(
+    labels: impl IntoIterator<Item = S>,
+    selected: usize,
+    class: impl Class<UiNode>,
+    button_class: impl AssetClass<UiButton>,
+    selected_class: impl AssetClass<UiButton>,
+    text_style: impl AssetClass<TextStyle>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    let mut container = UiNode::default();
+    class.apply(&mut container);
+    let mut unselected = UiButton::default();
+    button_class.apply(parent.assets(), &mut unselected);
+    let mut states = RadioButtonStates { selected: unselected.clone(), unselected };
+    selected_class.apply(parent.assets(), &mut states.selected);
+    let mut style = TextStyle::default();
+    text_style.apply(parent.assets(), &mut style);
+
+    let mut container = parent.spawn((container, extras));
+    container.insert_state(SelectedIndex(selected));
+    let group_entity = container.id();
+    let mut group = RadioGroup { buttons: Vec::new() };
+    container = container.with_children(|p| {
+        for (index, label) in labels.into_iter().enumerate() {
+            let button = RadioButton { group: group_entity, index };
+            let bundle = states.get(index == selected).clone();
+            let entity = p.spawn((bundle, states.clone(), button)).with_children(|p| {
+                text(label, (), style.clone(), p);
+            }).id();
+            group.buttons.push(entity);
+        }
+    });
+    container.insert(group);
+    container.id()
+}
@@ -0,0 +1,176 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::hash::{DefaultHasher, Hash, Hasher};
+use bevy_asset::AssetServer;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::{EntityCommands, SystemParam};
+use bevy_hierarchy::{BuildChildren, Children, DespawnRecursiveExt};
+use super::{Class, UiChildBuilder, UiEntityCommands, UiNode};
+
+
+/// Key given to a widget with [`UiChildBuilder::key`].
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct UiKey(u64);
+
+impl UiKey {
+    pub fn new(key: impl Hash) -> Self {
+        let mut hasher = DefaultHasher::new();
+        key.hash(&mut hasher);
+        Self(hasher.finish())
+    }
+}
+
+/// Identifies an entity spawned by a [`UiChildBuilder`], so that [`rebuild`] can match it to the entity that replaces it.
+/// Entities match when they were spawned with the same key, or without a key, from the same type of bundle.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct UiIdentity {
+    pub key: Option<UiKey>,
+    bundle: TypeId,
+}
+
+impl UiIdentity {
+    pub(crate) fn of<B: Any>(_bundle: &B, key: Option<UiKey>) -> Self {
+        Self { key, bundle: TypeId::of::<B>() }
+    }
+}
+
+/// Queries the existing entities of a UI tree, so that [`rebuild`] can reuse them.
+#[derive(SystemParam)]
+pub struct UiTree<'w, 's> {
+    children: Query<'w, 's, &'static Children>,
+    identities: Query<'w, 's, &'static UiIdentity>,
+}
+
+impl UiTree<'_, '_> {
+    /// Children of each entity in the tree under the root given.
+    fn snapshot(&self, root: Entity) -> Snapshot {
+        let mut snapshot = Snapshot(HashMap::new());
+        let mut parents = vec![root];
+        while let Some(parent) = parents.pop() {
+            let Ok(children) = self.children.get(parent) else { continue };
+            let children: Vec<(Entity, Option<UiIdentity>)> = children
+                .iter()
+                .map(|&child| (child, self.identities.get(child).ok().copied()))
+                .collect();
+            parents.extend(children.iter().map(|(child, _)| *child));
+            snapshot.0.insert(parent, children);
+        }
+        snapshot
+    }
+}
+
+/// Children of the entities of a UI tree, from before it is rebuilt.
+pub(crate) struct Snapshot(HashMap<Entity, Vec<(Entity, Option<UiIdentity>)>>);
+
+/// Matches the children spawned for a parent to its existing children, reusing them where it can.
+/// Kept for as long as the parent's [`UiEntityCommands`], so that children spawned over several
+/// [`with_children`](UiEntityCommands::with_children) calls, such as the cells of a [`grid`](crate::grid), are matched together.
+pub(crate) struct Reconciler {
+    parent: Entity,
+    /// Existing children, and whether each has been reused.
+    existing: Vec<(Entity, Option<UiIdentity>, bool)>,
+    /// Children in the order they were spawned.
+    children: Vec<Entity>,
+}
+
+impl Reconciler {
+    pub(crate) fn new(parent: Entity, snapshot: &Snapshot) -> Self {
+        let existing = snapshot.0
+            .get(&parent)
+            .map(|children| children.iter().map(|&(child, identity)| (child, identity, false)).collect())
+            .unwrap_or_default();
+        Self { parent, existing, children: Vec::new() }
+    }
+
+    /// Inserts the bundle into the first unused existing child with the same identity, or spawns a new child if there is none.
+    fn spawn(&mut self, commands: &mut Commands, bundle: impl Bundle, identity: UiIdentity) -> Entity {
+        let existing = self.existing
+            .iter_mut()
+            .find(|(_, existing, reused)| !*reused && *existing == Some(identity));
+        let entity = match existing {
+            Some((entity, _, reused)) => {
+                *reused = true;
+                commands.entity(*entity).insert((bundle, identity)).id()
+            }
+            None => commands.spawn((bundle, identity)).id(),
+        };
+        self.children.push(entity);
+        entity
+    }
+
+    /// Despawns the existing children that were not reused, and puts the rest in the order they were spawned.
+    /// Children that were not spawned by a [`UiChildBuilder`], such as the rows of a [`virtual_list`](crate::virtual_list), are kept after them.
+    pub(crate) fn finish(mut self, commands: &mut Commands) {
+        for (entity, identity, reused) in &self.existing {
+            match (identity, reused) {
+                (None, _) => self.children.push(*entity),
+                (Some(_), false) => commands.entity(*entity).despawn_recursive(),
+                (Some(_), true) => {}
+            }
+        }
+        commands.entity(self.parent).replace_children(&self.children);
+    }
+}
+
+/// Spawns the children of a parent being rebuilt through its [`Reconciler`].
+pub(crate) struct Rebuilder<'a, 'b> {
+    commands: Commands<'b, 'b>,
+    snapshot: &'a Snapshot,
+    reconciler: &'b mut Reconciler,
+}
+
+impl<'a, 'b> Rebuilder<'a, 'b> {
+    pub(crate) fn new(commands: Commands<'b, 'b>, snapshot: &'a Snapshot, reconciler: &'b mut Reconciler) -> Self {
+        Self { commands, snapshot, reconciler }
+    }
+
+    pub(crate) fn snapshot(&self) -> &'a Snapshot {
+        self.snapshot
+    }
+
+    pub(crate) fn spawn(&mut self, bundle: impl Bundle, identity: UiIdentity) -> EntityCommands<'_> {
+        let entity = self.reconciler.spawn(&mut self.commands, bundle, identity);
+        self.commands.entity(entity)
+    }
+}
+
+/// Re-runs the DSL closure that built a root [`UiNode`], reusing its existing entities rather than respawning them.
+/// Children are matched by the key given with [`UiChildBuilder::key`], or otherwise by the order they are spawned in.
+/// Matched entities have their bundles inserted anew, but keep their [`Interaction`](bevy_ui::Interaction) and the state of their widget,
+/// such as `Checked`, `TextInput`, `SliderValue`, `ActiveTab`, `SelectedIndex` and the selection of a `Dropdown`.
+/// Existing children that do not match are despawned, except those not spawned by a [`UiChildBuilder`].
+pub fn rebuild(
+    root: Entity,
+    class: impl Class<UiNode>,
+    assets: &AssetServer,
+    commands: &mut Commands,
+    tree: &UiTree,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    rebuildi(root, class, assets, commands, tree, (), children)
+}
+
+/// Re-runs the DSL closure that built a root [`UiNode`], reusing its existing entities rather than respawning them.
+/// Children are matched by the key given with [`UiChildBuilder::key`], or otherwise by the order they are spawned in.
+/// Matched entities have their bundles inserted anew, but keep their [`Interaction`](bevy_ui::Interaction) and the state of their widget,
+/// such as `Checked`, `TextInput`, `SliderValue`, `ActiveTab`, `SelectedIndex` and the selection of a `Dropdown`.
+/// Existing children that do not match are despawned, except those not spawned by a [`UiChildBuilder`].
+pub fn rebuildi(
+    root: Entity,
+    class: impl Class<UiNode>,
+    assets: &AssetServer,
+    commands: &mut Commands,
+    tree: &UiTree,
+    extras: impl Bundle,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    let mut bundle = UiNode::default();
+    class.apply(&mut bundle);
+    let snapshot = tree.snapshot(root);
+    let mut commands = commands.entity(root);
+    commands.insert((bundle, extras));
+    let root = UiEntityCommands { commands, assets, snapshot: Some(&snapshot), reconciler: None }
+        .with_children(children)
+        .id();
+    root
+}
@@ -0,0 +1,228 @@
+use bevy_ecs::prelude::*;
+use bevy_input::mouse::{MouseScrollUnit, MouseWheel};
+use bevy_ui::{
+    ComputedNode, FlexDirection, FocusPolicy, Interaction, Node, Overflow, PositionType,
+    RelativeCursorPosition, ScrollPosition, Val,
+};
+use super::{AssetClass, Class, UiButton, UiChildBuilder, UiEntityCommands, UiNode};
+
+
+/// Logical pixels scrolled per line of mouse wheel movement.
+pub const SCROLL_LINE_HEIGHT: f32 = 20.0;
+
+/// Entities that make up a [`scroll_view`], stored on its outermost node.
+/// To scroll programmatically, set the [`ScrollPosition`] of the viewport.
+#[derive(Component, Clone, Debug)]
+pub struct ScrollView {
+    /// Node that clips and scrolls the content.
+    pub viewport: Entity,
+    /// Node that holds the children of the scroll view.
+    pub content: Entity,
+    pub scrollbar: Option<Scrollbar>,
+    drag: Option<f32>,
+}
+
+impl ScrollView {
+    /// Furthest the viewport can scroll down, in logical pixels.
+    fn max_offset(&self, nodes: &Query<&ComputedNode>) -> f32 {
+        let (Ok(viewport), Ok(content)) = (nodes.get(self.viewport), nodes.get(self.content)) else { return 0.0 };
+        ((content.size().y - viewport.size().y) * viewport.inverse_scale_factor()).max(0.0)
+    }
+}
+
+/// Entities that make up the scrollbar of a [`scroll_view_bar`].
+#[derive(Clone, Debug)]
+pub struct Scrollbar {
+    pub track: Entity,
+    pub thumb: Entity,
+}
+
+/// Scrolls hovered scroll views with the mouse wheel.
+pub fn scroll_on_wheel(
+    mut wheel: EventReader<MouseWheel>,
+    views: Query<&ScrollView>,
+    nodes: Query<&ComputedNode>,
+    mut viewports: Query<(&mut ScrollPosition, &RelativeCursorPosition)>,
+) {
+    let delta: f32 = wheel
+        .read()
+        .map(|event| match event.unit {
+            MouseScrollUnit::Line => event.y * SCROLL_LINE_HEIGHT,
+            MouseScrollUnit::Pixel => event.y,
+        })
+        .sum();
+    if delta == 0.0 { return }
+    for view in &views {
+        let Ok((mut scroll, cursor)) = viewports.get_mut(view.viewport) else { continue };
+        if !cursor.mouse_over() { continue }
+        scroll.offset_y = (scroll.offset_y - delta).clamp(0.0, view.max_offset(&nodes));
+    }
+}
+
+/// Scrolls views while their content or scrollbar thumb is dragged.
+pub fn drag_scroll_views(
+    mut views: Query<&mut ScrollView>,
+    nodes: Query<&ComputedNode>,
+    interactions: Query<&Interaction>,
+    cursors: Query<&RelativeCursorPosition>,
+    mut scrolls: Query<&mut ScrollPosition>,
+) {
+    for mut view in &mut views {
+        // Content is dragged along with the cursor, the thumb is dragged along the track.
+        let (pressed, area, scale) = match &view.scrollbar {
+            Some(bar) if interactions.get(bar.thumb) == Ok(&Interaction::Pressed) => {
+                let (Ok(track), Ok(thumb)) = (nodes.get(bar.track), nodes.get(bar.thumb)) else { continue };
+                let travel = track.size().y - thumb.size().y;
+                let scale = if travel > 0.0 { -view.max_offset(&nodes) / (travel * track.inverse_scale_factor()) } else { 0.0 };
+                (true, bar.track, scale)
+            }
+            _ => (interactions.get(view.viewport) == Ok(&Interaction::Pressed), view.viewport, 1.0),
+        };
+        let cursor = cursors.get(area).ok().and_then(|cursor| cursor.normalized);
+        let (true, Some(cursor), Ok(node)) = (pressed, cursor, nodes.get(area)) else {
+            if view.drag.is_some() {
+                view.drag = None;
+            }
+            continue;
+        };
+        let position = cursor.y * node.size().y * node.inverse_scale_factor();
+        if let Some(last) = view.drag {
+            let max_offset = view.max_offset(&nodes);
+            if let Ok(mut scroll) = scrolls.get_mut(view.viewport) {
+                scroll.offset_y = (scroll.offset_y - (position - last) * scale).clamp(0.0, max_offset);
+            }
+        }
+        view.drag = Some(position);
+    }
+}
+
+/// Sizes and positions scrollbar thumbs to match the scroll position of their views.
+pub fn update_scrollbars(
+    views: Query<&ScrollView>,
+    nodes: Query<&ComputedNode>,
+    scrolls: Query<&ScrollPosition>,
+    mut thumbs: Query<&mut Node>,
+) {
+    for view in &views {
+        let Some(bar) = &view.scrollbar else { continue };
+        let (Ok(viewport), Ok(content), Ok(scroll)) = (nodes.get(view.viewport), nodes.get(view.content), scrolls.get(view.viewport)) else { continue };
+        let Ok(mut thumb) = thumbs.get_mut(bar.thumb) else { continue };
+        let visible = if content.size().y > 0.0 { (viewport.size().y / content.size().y).min(1.0) } else { 1.0 };
+        let max_offset = view.max_offset(&nodes);
+        let fraction = if max_offset > 0.0 { scroll.offset_y / max_offset } else { 0.0 };
+        let height = Val::Percent(visible * 100.0);
+        let top = Val::Percent(fraction * (1.0 - visible) * 100.0);
+        if thumb.height != height { thumb.height = height; }
+        if thumb.top != top { thumb.top = top; }
+    }
+}
+
+/// Spawns a [`UiNode`] that clips its children and scrolls them vertically on mouse wheel or drag.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn scroll_view(
+    class: impl Class<UiNode>,
+    parent: &mut UiChildBuilder,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    scroll_viewi(class, (), parent, children)
+}
+
+/// Spawns a [`UiNode`] that clips its children and scrolls them vertically on mouse wheel or drag.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn scroll_viewi(
+    class: impl Class<UiNode>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    spawn_scroll_view(class, scroll_content(), None::<((), ())>, extras, |_| {}, parent, children)
+}
+
+/// Spawns a [`UiNode`] that clips its children and scrolls them vertically on mouse wheel or drag,
+/// next to a scrollbar whose thumb can be dragged.
+/// The thumb is positioned absolutely within the track, and sized to match the visible portion of the content.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn scroll_view_bar(
+    class: impl Class<UiNode>,
+    track_class: impl Class<UiNode>,
+    thumb_class: impl AssetClass<UiButton>,
+    parent: &mut UiChildBuilder,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    scroll_view_bari(class, track_class, thumb_class, (), parent, children)
+}
+
+/// Spawns a [`UiNode`] that clips its children and scrolls them vertically on mouse wheel or drag,
+/// next to a scrollbar whose thumb can be dragged.
+/// The thumb is positioned absolutely within the track, and sized to match the visible portion of the content.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn scroll_view_bari(
+    class: impl Class<UiNode>,
+    track_class: impl Class<UiNode>,
+    thumb_class: impl AssetClass<UiButton>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    spawn_scroll_view(class, scroll_content(), Some((track_class, thumb_class)), extras, |_| {}, parent, children)
+}
+
+/// Node that holds the children of a scroll view.
+pub(crate) fn scroll_content() -> UiNode {
+    let mut content = UiNode::default();
+    content.node.width = Val::Percent(100.0);
+    content.node.flex_direction = FlexDirection::Column;
+    content.node.flex_shrink = 0.0;
+    content
+}
+
+/// Spawns a scroll view, letting the widget built on top of it add to the container with `configure`.
+pub(crate) fn spawn_scroll_view<T: Class<UiNode>, H: AssetClass<UiButton>>(
+    class: impl Class<UiNode>,
+    content: UiNode,
+    scrollbar: Option<(T, H)>,
+    extras: impl Bundle,
+    configure: impl FnOnce(&mut UiEntityCommands),
+    parent: &mut UiChildBuilder,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    let mut container = UiNode::default();
+    class.apply(&mut container);
+    let mut viewport = UiNode::default();
+    viewport.node.flex_grow = 1.0;
+    viewport.node.overflow = Overflow::scroll_y();
+
+    let mut viewport_entity = Entity::PLACEHOLDER;
+    let mut content_entity = Entity::PLACEHOLDER;
+    let mut bar = None;
+    let mut container = parent.spawn((container, extras));
+    container = container.with_children(|p| {
+        let mut viewport = p.spawn((viewport, RelativeCursorPosition::default()));
+        viewport.insert_state(Interaction::default());
+        viewport_entity = viewport.id();
+        viewport.with_children(|p| {
+            content_entity = p.spawn(content).with_children(children).id();
+        });
+        let Some((track_class, thumb_class)) = scrollbar else { return };
+        let mut track = UiNode::default();
+        track_class.apply(&mut track);
+        let mut thumb = UiButton::default();
+        thumb_class.apply(p.assets(), &mut thumb);
+        thumb.node.position_type = PositionType::Absolute;
+        thumb.node.width = Val::Percent(100.0);
+        thumb.focus_policy = FocusPolicy::Block;
+        let track = p.spawn((track, RelativeCursorPosition::default()));
+        let track_entity = track.id();
+        track.with_children(|p| {
+            bar = Some(Scrollbar { track: track_entity, thumb: p.spawn(thumb).id() });
+        });
+    });
+    container.insert(ScrollView {
+        viewport: viewport_entity,
+        content: content_entity,
+        scrollbar: bar,
+        drag: None,
+    });
+    configure(&mut container);
+    container.id()
+}
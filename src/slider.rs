@@ -0,0 +1,180 @@
+use std::ops::RangeInclusive;
+use bevy_ecs::prelude::*;
+use bevy_ui::{AlignItems, ComputedNode, FocusPolicy, Interaction, Node, PositionType, RelativeCursorPosition, Val};
+use super::{AssetClass, Class, Disabled, UiButton, UiChildBuilder, UiNode};
+
+
+/// Range and step of a [`slider`], stored on its track.
+#[derive(Component, Clone, Debug)]
+pub struct Slider {
+    pub min: f32,
+    pub max: f32,
+    /// Increment values snap to. Values are continuous when zero.
+    pub step: f32,
+    /// Thumb entity that gets dragged along the track.
+    pub thumb: Entity,
+}
+
+impl Slider {
+    /// Snaps a value to the step of this slider and clamps it to its range.
+    /// A range whose min is above its max clamps to the min.
+    pub fn snap(&self, value: f32) -> f32 {
+        let value = if self.step > 0.0 {
+            self.min + ((value - self.min) / self.step).round() * self.step
+        }
+        else {
+            value
+        };
+        value.min(self.max).max(self.min)
+    }
+
+    /// Where a value lies in the range of this slider, from 0 to 1.
+    pub fn fraction(&self, value: f32) -> f32 {
+        if self.max > self.min {
+            ((value - self.min) / (self.max - self.min)).clamp(0.0, 1.0)
+        }
+        else {
+            0.0
+        }
+    }
+}
+
+/// Current value of a [`slider`].
+/// Updated when the track is dragged. Can also be set directly to move the thumb.
+#[derive(Component, Copy, Clone, PartialEq, Debug, Default)]
+pub struct SliderValue(pub f32);
+
+/// Sent when the user drags a [`slider`] to a new value.
+#[derive(Event, Copy, Clone, PartialEq, Debug)]
+pub struct SliderChanged {
+    pub entity: Entity,
+    pub value: f32,
+}
+
+/// Sets [`SliderValue`] from the cursor position while a slider's track is pressed, and sends a [`SliderChanged`] event.
+#[allow(clippy::type_complexity)]
+pub fn drag_sliders(
+    mut sliders: Query<
+        (Entity, &Slider, &mut SliderValue, &Interaction, &RelativeCursorPosition, &ComputedNode),
+        Without<Disabled>,
+    >,
+    thumbs: Query<&ComputedNode>,
+    mut events: EventWriter<SliderChanged>,
+) {
+    for (entity, slider, mut value, interaction, cursor, track) in &mut sliders {
+        if *interaction != Interaction::Pressed { continue }
+        let Some(cursor) = cursor.normalized else { continue };
+        let thumb_width = thumbs.get(slider.thumb).map(|thumb| thumb.size().x).unwrap_or(0.0);
+        let travel = track.size().x - thumb_width;
+        if travel <= 0.0 { continue }
+        let fraction = ((cursor.x * track.size().x - thumb_width / 2.0) / travel).clamp(0.0, 1.0);
+        let new_value = slider.snap(slider.min + fraction * (slider.max - slider.min));
+        if value.set_if_neq(SliderValue(new_value)) {
+            events.send(SliderChanged { entity, value: new_value });
+        }
+    }
+}
+
+/// Moves the thumb of each slider to match its [`SliderValue`].
+#[allow(clippy::type_complexity)]
+pub fn position_slider_thumbs(
+    sliders: Query<(&Slider, &SliderValue, &ComputedNode), Or<(Changed<SliderValue>, Changed<ComputedNode>)>>,
+    mut thumbs: Query<(&mut Node, &ComputedNode)>,
+) {
+    for (slider, value, track) in &sliders {
+        let Ok((mut thumb_node, thumb)) = thumbs.get_mut(slider.thumb) else { continue };
+        let travel = (track.size().x - thumb.size().x).max(0.0);
+        let left = slider.fraction(value.0) * travel * track.inverse_scale_factor();
+        thumb_node.left = Val::Px(left);
+    }
+}
+
+/// Spawns a [`UiNode`] track with a [`UiButton`] thumb that can be dragged to pick a value within a range.
+/// The thumb is positioned absolutely within the track, and lets presses through to it.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn slider(
+    value: f32,
+    range: RangeInclusive<f32>,
+    step: f32,
+    class: impl Class<UiNode>,
+    thumb_class: impl AssetClass<UiButton>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    slideri(value, range, step, class, thumb_class, (), parent)
+}
+
+/// Spawns a [`UiNode`] track with a [`UiButton`] thumb that can be dragged to pick a value within a range.
+/// The thumb is positioned absolutely within the track, and lets presses through to it.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn slideri(
+    value: f32,
+    range: RangeInclusive<f32>,
+    step: f32,
+    class: impl Class<UiNode>,
+    thumb_class: impl AssetClass<UiButton>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    let mut track = UiNode::default();
+    track.node.align_items = AlignItems::Center;
+    class.apply(&mut track);
+    let mut thumb = UiButton::default();
+    thumb_class.apply(parent.assets(), &mut thumb);
+    thumb.node.position_type = PositionType::Absolute;
+    thumb.focus_policy = FocusPolicy::Pass;
+
+    let mut track = parent.spawn((track, RelativeCursorPosition::default(), extras));
+    track.insert_state(Interaction::default());
+    let mut thumb_entity = Entity::PLACEHOLDER;
+    track = track.with_children(|p| {
+        thumb_entity = p.spawn(thumb).id();
+    });
+    // Reversed ranges, ie: `1.0..=0.0`, are flipped rather than left for snap to reject
+    let (start, end) = range.into_inner();
+    let slider = Slider {
+        min: start.min(end),
+        max: start.max(end),
+        step,
+        thumb: thumb_entity,
+    };
+    let value = SliderValue(slider.snap(value));
+    track.insert(slider).insert_state(value);
+    track.id()
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::entity::Entity;
+    use super::Slider;
+
+    fn slider(min: f32, max: f32, step: f32) -> Slider {
+        Slider { min, max, step, thumb: Entity::PLACEHOLDER }
+    }
+
+    #[test]
+    fn snap_to_step() {
+        let slider = slider(0.0, 1.0, 0.25);
+        assert_eq!(slider.snap(0.3), 0.25);
+        assert_eq!(slider.snap(0.4), 0.5);
+        assert_eq!(slider.snap(2.0), 1.0);
+        assert_eq!(slider.snap(-1.0), 0.0);
+    }
+
+    #[test]
+    fn continuous_without_step() {
+        assert_eq!(slider(0.0, 1.0, 0.0).snap(0.3), 0.3);
+    }
+
+    #[test]
+    fn reversed_range_does_not_panic() {
+        assert_eq!(slider(1.0, 0.0, 0.0).snap(0.5), 1.0);
+        assert_eq!(slider(1.0, 0.0, 0.0).fraction(0.5), 0.0);
+    }
+
+    #[test]
+    fn fraction() {
+        let slider = slider(10.0, 20.0, 1.0);
+        assert_eq!(slider.fraction(15.0), 0.5);
+        assert_eq!(slider.fraction(30.0), 1.0);
+    }
+}
@@ -0,0 +1,165 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::Interaction;
+use super::{AssetClass, TextStyle, UiButton, UiChildBuilder, text};
+
+
+/// Marks a button as disabled.
+/// Disabled buttons use their disabled style and ignore interactions.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct Disabled;
+
+/// Styles a button takes on in each of its interaction states.
+/// Inserted by [`state_button`] and its variants.
+#[derive(Component, Clone, Debug)]
+pub struct ButtonStates {
+    pub normal: UiButton,
+    pub hovered: UiButton,
+    pub pressed: UiButton,
+    pub disabled: UiButton,
+}
+
+impl ButtonStates {
+    /// Style that matches the state given.
+    pub fn get(&self, interaction: Interaction, disabled: bool) -> &UiButton {
+        if disabled {
+            return &self.disabled;
+        }
+        match interaction {
+            Interaction::Pressed => &self.pressed,
+            Interaction::Hovered => &self.hovered,
+            Interaction::None => &self.normal,
+        }
+    }
+}
+
+/// Re-inserts the style matching the current state of each button.
+#[allow(clippy::type_complexity)]
+pub fn apply_button_states(
+    changed: Query<
+        (Entity, &ButtonStates, &Interaction, Has<Disabled>),
+        Or<(Changed<Interaction>, Changed<ButtonStates>, Added<Disabled>)>,
+    >,
+    buttons: Query<(&ButtonStates, &Interaction)>,
+    mut enabled: RemovedComponents<Disabled>,
+    mut commands: Commands,
+) {
+    for (entity, states, interaction, disabled) in &changed {
+        commands.entity(entity).insert(states.get(*interaction, disabled).clone());
+    }
+    for entity in enabled.read() {
+        if let Ok((states, interaction)) = buttons.get(entity) {
+            commands.entity(entity).insert(states.get(*interaction, false).clone());
+        }
+    }
+}
+
+/// Spawns a [`UiButton`] with children that is restyled as its [`Interaction`] changes.
+/// The hovered, pressed and disabled classes are each applied on top of the base class.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn state_button(
+    class: impl AssetClass<UiButton>,
+    hovered: impl AssetClass<UiButton>,
+    pressed: impl AssetClass<UiButton>,
+    disabled: impl AssetClass<UiButton>,
+    parent: &mut UiChildBuilder,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    state_buttoni(class, hovered, pressed, disabled, (), parent, children)
+}
+
+/// Spawns a [`UiButton`] with children that is restyled as its [`Interaction`] changes.
+/// The hovered, pressed and disabled classes are each applied on top of the base class.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn state_buttoni(
+    class: impl AssetClass<UiButton>,
+    hovered: impl AssetClass<UiButton>,
+    pressed: impl AssetClass<UiButton>,
+    disabled: impl AssetClass<UiButton>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder,
+    children: impl FnOnce(&mut UiChildBuilder)
+) -> Entity {
+    let states = button_states(class, hovered, pressed, disabled, parent);
+    parent
+        .spawn((states.normal.clone(), states, extras))
+        .with_children(children).id()
+}
+
+/// Spawns a [`UiButton`] with a single [`UiText`](crate::UiText) as its child that is restyled as its [`Interaction`] changes.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn state_text_button(
+    txt: impl Into<String>,
+    class: impl AssetClass<UiButton>,
+    hovered: impl AssetClass<UiButton>,
+    pressed: impl AssetClass<UiButton>,
+    disabled: impl AssetClass<UiButton>,
+    text_style: impl AssetClass<TextStyle>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    state_text_buttoni(txt, class, hovered, pressed, disabled, text_style, (), parent)
+}
+
+/// Spawns a [`UiButton`] with a single [`UiText`](crate::UiText) as its child that is restyled as its [`Interaction`] changes.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+#[allow(clippy::too_many_arguments)]
+pub fn state_text_buttoni(
+    txt: impl Into<String>,
+    class: impl AssetClass<UiButton>,
+    hovered: impl AssetClass<UiButton>,
+    pressed: impl AssetClass<UiButton>,
+    disabled: impl AssetClass<UiButton>,
+    text_style: impl AssetClass<TextStyle>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    state_buttoni(class, hovered, pressed, disabled, extras, parent, |p| {
+        text(txt, (), text_style, p);
+    })
+}
+
+fn button_states(
+    class: impl AssetClass<UiButton>,
+    hovered: impl AssetClass<UiButton>,
+    pressed: impl AssetClass<UiButton>,
+    disabled: impl AssetClass<UiButton>,
+    parent: &UiChildBuilder,
+) -> ButtonStates {
+    let assets = parent.assets();
+    let mut normal = UiButton::default();
+    class.apply(assets, &mut normal);
+    let mut states = ButtonStates {
+        hovered: normal.clone(),
+        pressed: normal.clone(),
+        disabled: normal.clone(),
+        normal,
+    };
+    hovered.apply(assets, &mut states.hovered);
+    pressed.apply(assets, &mut states.pressed);
+    disabled.apply(assets, &mut states.disabled);
+    states
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_color::Color;
+    use bevy_ui::Interaction;
+    use super::{ButtonStates, UiButton};
+
+    fn button(color: Color) -> UiButton {
+        UiButton { background_color: color.into(), ..Default::default() }
+    }
+
+    #[test]
+    fn disabled_overrides_interaction() {
+        let states = ButtonStates {
+            normal: button(Color::BLACK),
+            hovered: button(Color::WHITE),
+            pressed: button(Color::srgb(1.0, 0.0, 0.0)),
+            disabled: button(Color::srgb(0.5, 0.5, 0.5)),
+        };
+        assert_eq!(states.get(Interaction::None, false).background_color, Color::BLACK.into());
+        assert_eq!(states.get(Interaction::Hovered, false).background_color, Color::WHITE.into());
+        assert_eq!(states.get(Interaction::Pressed, false).background_color, Color::srgb(1.0, 0.0, 0.0).into());
+        assert_eq!(states.get(Interaction::Pressed, true).background_color, Color::srgb(0.5, 0.5, 0.5).into());
+    }
+}
@@ -0,0 +1,366 @@
+use std::error::Error;
+use std::fmt;
+use bevy_asset::{Asset, AssetEvent, AssetLoader, AssetServer, Assets, Handle, LoadContext};
+use bevy_asset::io::Reader;
+use bevy_color::{Color, Srgba};
+use bevy_ecs::component::ComponentId;
+use bevy_ecs::event::EventCursor;
+use bevy_ecs::prelude::*;
+use bevy_ecs::reflect::AppTypeRegistry;
+use bevy_hierarchy::Children;
+use bevy_log::error;
+use bevy_reflect::TypePath;
+use bevy_text::{TextColor, TextFont};
+use bevy_ui::widget::{Button, ImageNode, Text};
+use bevy_ui::{
+    AlignContent, AlignItems, AlignSelf, BackgroundColor, BorderColor, BorderRadius, Display, FlexDirection, FlexWrap,
+    JustifyContent, JustifyItems, JustifySelf, Node, Overflow, PositionType, UiRect, Val,
+};
+use serde::{Deserialize, Deserializer};
+use super::UiIdentity;
+
+
+/// CSS-like style sheet loaded from a `.style.ron` or `.style.json` file.
+/// Made active by adding its handle to the [`StyleSheets`] resource.
+#[derive(Asset, TypePath, Deserialize, Clone, Debug, Default)]
+pub struct StyleSheet {
+    #[serde(default)]
+    pub rules: Vec<StyleRule>,
+}
+
+/// Rule of a [`StyleSheet`], which sets the fields given on the entities it selects.
+/// Fields left out are left as they are.
+#[derive(Deserialize, Clone, Debug, Default)]
+pub struct StyleRule {
+    /// Selectors an entity must all match for the rule to apply to it.
+    pub select: Vec<Selector>,
+    #[serde(default)]
+    pub node: NodeRule,
+    /// Hex color, ie: `"#224422"`.
+    #[serde(default, deserialize_with = "hex_color")]
+    pub background_color: Option<Color>,
+    /// Hex color, ie: `"#224422"`.
+    #[serde(default, deserialize_with = "hex_color")]
+    pub border_color: Option<Color>,
+    /// Radius of every corner.
+    #[serde(default)]
+    pub border_radius: Option<Val>,
+    /// Path of the image of an image or button.
+    #[serde(default)]
+    pub image: Option<String>,
+    /// Applies to the text of the entity selected, or to the texts among its children, such as the label of a [`text_button`](crate::text_button).
+    #[serde(default)]
+    pub text: TextRule,
+}
+
+/// Selects entities in a [`StyleRule`].
+#[derive(Deserialize, Clone, Eq, PartialEq, Debug)]
+pub enum Selector {
+    /// Entities with a class name in their [`UiClasses`].
+    Class(String),
+    /// Entities spawned by a type of widget.
+    Widget(WidgetKind),
+    /// Entities with a component of the type named, ie: `Component("MainMenu")`.
+    /// The component must derive `Reflect` and be registered with `App::register_type`.
+    Component(String),
+}
+
+/// Type of widget matched by [`Selector::Widget`].
+#[derive(Deserialize, Copy, Clone, Eq, PartialEq, Debug)]
+pub enum WidgetKind {
+    /// Nodes that are neither texts, buttons nor images, such as those spawned by [`node`](crate::node).
+    Node,
+    Text,
+    Button,
+    /// Images that are not buttons, such as those spawned by [`image`](crate::image).
+    Image,
+}
+
+/// Fields of a [`Node`] set by a [`StyleRule`].
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct NodeRule {
+    pub display: Option<Display>,
+    pub position_type: Option<PositionType>,
+    pub overflow: Option<Overflow>,
+    pub left: Option<Val>,
+    pub right: Option<Val>,
+    pub top: Option<Val>,
+    pub bottom: Option<Val>,
+    pub width: Option<Val>,
+    pub height: Option<Val>,
+    pub min_width: Option<Val>,
+    pub min_height: Option<Val>,
+    pub max_width: Option<Val>,
+    pub max_height: Option<Val>,
+    pub align_items: Option<AlignItems>,
+    pub justify_items: Option<JustifyItems>,
+    pub align_self: Option<AlignSelf>,
+    pub justify_self: Option<JustifySelf>,
+    pub align_content: Option<AlignContent>,
+    pub justify_content: Option<JustifyContent>,
+    pub margin: Option<UiRect>,
+    pub padding: Option<UiRect>,
+    pub border: Option<UiRect>,
+    pub flex_direction: Option<FlexDirection>,
+    pub flex_wrap: Option<FlexWrap>,
+    pub flex_grow: Option<f32>,
+    pub flex_shrink: Option<f32>,
+    pub flex_basis: Option<Val>,
+    pub row_gap: Option<Val>,
+    pub column_gap: Option<Val>,
+}
+
+/// Fields of a [`TextStyle`](crate::TextStyle) set by a [`StyleRule`].
+#[derive(Deserialize, Clone, Debug, Default)]
+#[serde(default)]
+pub struct TextRule {
+    /// Path of the font.
+    pub font: Option<String>,
+    pub font_size: Option<f32>,
+    /// Hex color, ie: `"#ffffff"`.
+    #[serde(deserialize_with = "hex_color")]
+    pub color: Option<Color>,
+}
+
+fn hex_color<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Color>, D::Error> {
+    let Some(hex) = Option::<String>::deserialize(deserializer)? else { return Ok(None) };
+    Srgba::hex(&hex)
+        .map(|color| Some(color.into()))
+        .map_err(|err| serde::de::Error::custom(format!("invalid color '{hex}': {err}")))
+}
+
+/// Style sheets applied to every DSL widget, in order.
+/// Rules are applied after the classes a widget was spawned with, as though they were classes appended to them,
+/// with later rules taking precedence over earlier ones.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct StyleSheets(pub Vec<Handle<StyleSheet>>);
+
+/// Class names, separated by whitespace, matched by [`Selector::Class`].
+/// Inserted by [`layout`](crate::layout) with the class names of each element, and added as extras to other widgets, ie:
+/// `nodei(c_panel, UiClasses::new("panel"), p, |p| ...)`.
+#[derive(Component, Clone, Eq, PartialEq, Debug, Default)]
+pub struct UiClasses(pub String);
+
+impl UiClasses {
+    pub fn new(names: impl Into<String>) -> Self {
+        Self(names.into())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.0.split_whitespace().any(|n| n == name)
+    }
+}
+
+/// Error loading a [`StyleSheet`] file.
+#[derive(Debug)]
+pub enum StyleSheetLoaderError {
+    Io(std::io::Error),
+    /// The `.style.ron` file is malformed. Includes the line and column of the error.
+    Ron(ron::error::SpannedError),
+    /// The `.style.json` file is malformed. Includes the line and column of the error.
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for StyleSheetLoaderError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Io(err) => write!(f, "could not read style sheet: {err}"),
+            Self::Ron(err) => write!(f, "malformed RON style sheet: {err}"),
+            Self::Json(err) => write!(f, "malformed JSON style sheet: {err}"),
+        }
+    }
+}
+
+impl Error for StyleSheetLoaderError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Io(err) => Some(err),
+            Self::Ron(err) => Some(err),
+            Self::Json(err) => Some(err),
+        }
+    }
+}
+
+/// Loads [`StyleSheet`] assets from `.style.ron` and `.style.json` files.
+/// RON fields are implicitly optional, so that rules can be written as `width: Px(50.0)` rather than `width: Some(Px(50.0))`.
+#[derive(Default)]
+pub struct StyleSheetLoader;
+
+impl AssetLoader for StyleSheetLoader {
+    type Asset = StyleSheet;
+    type Settings = ();
+    type Error = StyleSheetLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<StyleSheet, StyleSheetLoaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await.map_err(StyleSheetLoaderError::Io)?;
+        let json = load_context.path().extension().is_some_and(|extension| extension == "json");
+        if json {
+            serde_json::from_slice(&bytes).map_err(StyleSheetLoaderError::Json)
+        }
+        else {
+            ron::Options::default()
+                .with_default_extension(ron::extensions::Extensions::IMPLICIT_SOME)
+                .from_bytes(&bytes)
+                .map_err(StyleSheetLoaderError::Ron)
+        }
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["style.ron", "style.json"]
+    }
+}
+
+/// Applies the [`StyleSheets`] to widgets that were spawned, rebuilt, had their [`UiClasses`] changed,
+/// were given a component selected by a rule, or are buttons restyled by their state since the last run.
+/// Other changes to a [`Node`], such as those made by widgets like [`tabs`](crate::tabs), are left alone.
+/// Every widget is restyled when the style sheets change, such as when a file is edited with hot reloading enabled.
+/// Fields a rule stops setting keep the value it last set, until the widget is rebuilt.
+pub fn apply_style_sheets(world: &mut World, mut cursor: Local<EventCursor<AssetEvent<StyleSheet>>>) {
+    let sheets_changed = cursor.read(world.resource::<Events<AssetEvent<StyleSheet>>>()).count() > 0;
+    let restyle_all = sheets_changed || world.is_resource_changed::<StyleSheets>();
+
+    // Resolves the components named by selectors
+    let components: Vec<(String, Option<ComponentId>)> = {
+        let sheets = world.resource::<Assets<StyleSheet>>();
+        let registry = world.resource::<AppTypeRegistry>().read();
+        world.resource::<StyleSheets>().0
+            .iter()
+            .filter_map(|handle| sheets.get(handle))
+            .flat_map(|sheet| &sheet.rules)
+            .flat_map(|rule| &rule.select)
+            .filter_map(|selector| match selector {
+                Selector::Component(name) => Some(name),
+                _ => None,
+            })
+            .map(|name| {
+                let id = registry
+                    .get_with_short_type_path(name)
+                    .or_else(|| registry.get_with_type_path(name))
+                    .and_then(|registration| world.components().get_id(registration.type_id()));
+                (name.clone(), id)
+            })
+            .collect()
+    };
+    if restyle_all {
+        for (name, _) in components.iter().filter(|(_, id)| id.is_none()) {
+            error!("Unknown component '{name}' in style sheet");
+        }
+    }
+
+    // Buttons are restyled when their state re-inserts their UiButton, such as by apply_button_states
+    let mut entities: Vec<Entity> = if restyle_all {
+        world.query_filtered::<Entity, With<Node>>().iter(world).collect()
+    }
+    else {
+        world
+            .query_filtered::<Entity, (With<Node>, Or<(Added<Node>, Changed<UiIdentity>, Changed<UiClasses>, Changed<Button>)>)>()
+            .iter(world)
+            .collect()
+    };
+
+    // Selected components inserted since the last run, such as markers
+    if !restyle_all {
+        let (last_run, this_run) = (world.last_change_tick(), world.read_change_tick());
+        for id in components.iter().filter_map(|(_, id)| *id) {
+            let mut query = QueryBuilder::<Entity, With<Node>>::new(world).with_id(id).build();
+            let candidates: Vec<Entity> = query.iter(world).collect();
+            entities.extend(candidates.into_iter().filter(|entity| {
+                world.entity(*entity).get_change_ticks_by_id(id).is_some_and(|ticks| ticks.is_added(last_run, this_run))
+            }));
+        }
+        entities.sort();
+        entities.dedup();
+    }
+    if entities.is_empty() { return }
+
+    let rules: Vec<StyleRule> = {
+        let sheets = world.resource::<Assets<StyleSheet>>();
+        world.resource::<StyleSheets>().0
+            .iter()
+            .filter_map(|handle| sheets.get(handle))
+            .flat_map(|sheet| sheet.rules.iter().cloned())
+            .collect()
+    };
+    let assets = world.resource::<AssetServer>().clone();
+    for entity in entities {
+        for rule in &rules {
+            if rule.select.iter().all(|selector| matches(world, entity, selector, &components)) {
+                apply_rule(world, entity, rule, &assets);
+            }
+        }
+    }
+}
+
+fn matches(world: &World, entity: Entity, selector: &Selector, components: &[(String, Option<ComponentId>)]) -> bool {
+    let entity = world.entity(entity);
+    match selector {
+        Selector::Class(name) => entity.get::<UiClasses>().is_some_and(|classes| classes.contains(name)),
+        Selector::Widget(kind) => {
+            let (text, button, image) = (entity.contains::<Text>(), entity.contains::<Button>(), entity.contains::<ImageNode>());
+            match kind {
+                WidgetKind::Node => !text && !button && !image,
+                WidgetKind::Text => text,
+                WidgetKind::Button => button,
+                WidgetKind::Image => image && !button,
+            }
+        }
+        Selector::Component(name) => components
+            .iter()
+            .find(|(component, _)| component == name)
+            .and_then(|(_, id)| *id)
+            .is_some_and(|id| entity.contains_id(id)),
+    }
+}
+
+fn apply_rule(world: &mut World, entity: Entity, rule: &StyleRule, assets: &AssetServer) {
+    if let Some(mut node) = world.get_mut::<Node>(entity) {
+        rule.node.apply(&mut node);
+    }
+    if let (Some(color), Some(mut background)) = (rule.background_color, world.get_mut::<BackgroundColor>(entity)) {
+        background.0 = color;
+    }
+    if let (Some(color), Some(mut border)) = (rule.border_color, world.get_mut::<BorderColor>(entity)) {
+        border.0 = color;
+    }
+    if let (Some(radius), Some(mut border_radius)) = (rule.border_radius, world.get_mut::<BorderRadius>(entity)) {
+        *border_radius = BorderRadius::all(radius);
+    }
+    if let (Some(path), Some(mut image)) = (&rule.image, world.get_mut::<ImageNode>(entity)) {
+        image.image = assets.load(path);
+    }
+
+    // Text fields reach the texts among the children of entities without text of their own
+    let texts: Vec<Entity> = match world.entity(entity).contains::<TextFont>() {
+        true => vec![entity],
+        false => world.get::<Children>(entity).map(|children| children.to_vec()).unwrap_or_default(),
+    };
+    for text in texts {
+        if let Some(mut font) = world.get_mut::<TextFont>(text) {
+            if let Some(path) = &rule.text.font { font.font = assets.load(path) }
+            if let Some(size) = rule.text.font_size { font.font_size = size }
+        }
+        if let (Some(color), Some(mut text_color)) = (rule.text.color, world.get_mut::<TextColor>(text)) {
+            text_color.0 = color;
+        }
+    }
+}
+
+impl NodeRule {
+    fn apply(&self, node: &mut Node) {
+        macro_rules! set {
+            ($($field:ident),*) => { $(if let Some(value) = self.$field { node.$field = value })* };
+        }
+        set!(
+            display, position_type, overflow, left, right, top, bottom, width, height, min_width, min_height, max_width, max_height,
+            align_items, justify_items, align_self, justify_self, align_content, justify_content, margin, padding, border,
+            flex_direction, flex_wrap, flex_grow, flex_shrink, flex_basis, row_gap, column_gap
+        );
+    }
+}
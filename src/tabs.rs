@@ -0,0 +1,151 @@
+use bevy_ecs::prelude::*;
+use bevy_ui::{Display, FlexDirection, Interaction, Node};
+use super::{AssetClass, Class, Disabled, TextStyle, UiButton, UiChildBuilder, UiNode, text};
+
+
+/// Index of the tab whose content is showing in a [`tabs`] widget.
+/// Set when a header is pressed. Can also be set directly to switch tabs.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct ActiveTab(pub usize);
+
+/// Headers and pages of a [`tabs`] widget, stored on its outermost node.
+#[derive(Component, Clone, Debug)]
+pub struct Tabs {
+    pub headers: Vec<Entity>,
+    pub pages: Vec<Entity>,
+}
+
+/// Header button of a [`tabs`] widget.
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TabHeader {
+    pub tabs: Entity,
+    pub index: usize,
+}
+
+/// Styles a tab header takes on when inactive and active.
+#[derive(Component, Clone, Debug)]
+pub struct TabHeaderStates {
+    pub inactive: UiButton,
+    pub active: UiButton,
+}
+
+/// Sent when the user switches to another tab.
+#[derive(Event, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TabChanged {
+    pub entity: Entity,
+    pub active: usize,
+}
+
+/// Sets [`ActiveTab`] when a header is pressed, and sends a [`TabChanged`] event.
+#[allow(clippy::type_complexity)]
+pub fn select_tabs(
+    headers: Query<(&TabHeader, &Interaction), (Changed<Interaction>, Without<Disabled>)>,
+    mut tabs: Query<&mut ActiveTab>,
+    mut events: EventWriter<TabChanged>,
+) {
+    for (header, interaction) in &headers {
+        if *interaction != Interaction::Pressed { continue }
+        let Ok(mut active) = tabs.get_mut(header.tabs) else { continue };
+        if active.set_if_neq(ActiveTab(header.index)) {
+            events.send(TabChanged { entity: header.tabs, active: header.index });
+        }
+    }
+}
+
+/// Shows the page of the active tab, hides the others, and restyles the headers.
+pub fn render_tabs(
+    tabs: Query<(&Tabs, &ActiveTab), Changed<ActiveTab>>,
+    headers: Query<&TabHeaderStates>,
+    mut pages: Query<&mut Node>,
+    mut commands: Commands,
+) {
+    for (tabs, active) in &tabs {
+        for (index, page) in tabs.pages.iter().enumerate() {
+            let Ok(mut page) = pages.get_mut(*page) else { continue };
+            let display = if index == active.0 { Display::Flex } else { Display::None };
+            if page.display != display {
+                page.display = display;
+            }
+        }
+        for (index, header) in tabs.headers.iter().enumerate() {
+            let Ok(states) = headers.get(*header) else { continue };
+            let style = if index == active.0 { &states.active } else { &states.inactive };
+            commands.entity(*header).insert(style.clone());
+        }
+    }
+}
+
+/// Spawns a [`UiNode`] with a row of header buttons, one for each label, above the pages of each tab.
+/// Only the page of the active tab is shown. The callback function argument spawns the contents of the page at the index given.
+/// The active header class is applied on top of the header class.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+#[allow(clippy::too_many_arguments)]
+pub fn tabs<S: Into<String>>(
+    labels: impl IntoIterator<Item = S>,
+    active: usize,
+    class: impl Class<UiNode>,
+    header_class: impl AssetClass<UiButton>,
+    active_header_class: impl AssetClass<UiButton>,
+    text_style: impl AssetClass<TextStyle>,
+    parent: &mut UiChildBuilder,
+    children: impl FnMut(&mut UiChildBuilder, usize)
+) -> Entity {
+    tabsi(labels, active, class, header_class, active_header_class, text_style, (), parent, children)
+}
+
+/// Spawns a [`UiNode`] with a row of header buttons, one for each label, above the pages of each tab.
+/// Only the page of the active tab is shown. The callback function argument spawns the contents of the page at the index given.
+/// The active header class is applied on top of the header class.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+#[allow(clippy::too_many_arguments)]
+pub fn tabsi<S: Into<String>>(
+    labels: impl IntoIterator<Item = S>,
+    active: usize,
+    class: impl Class<UiNode>,
+    header_class: impl AssetClass<UiButton>,
+    active_header_class: impl AssetClass<UiButton>,
+    text_style: impl AssetClass<TextStyle>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder,
+    mut children: impl FnMut(&mut UiChildBuilder, usize)
+) -> Entity {
+    let labels: Vec<String> = labels.into_iter().map(Into::into).collect();
+    let mut container = UiNode::default();
+    container.node.flex_direction = FlexDirection::Column;
+    class.apply(&mut container);
+    let mut inactive = UiButton::default();
+    header_class.apply(parent.assets(), &mut inactive);
+    let mut states = TabHeaderStates { active: inactive.clone(), inactive };
+    active_header_class.apply(parent.assets(), &mut states.active);
+    let mut style = TextStyle::default();
+    text_style.apply(parent.assets(), &mut style);
+
+    let mut container = parent.spawn((container, extras));
+    container.insert_state(ActiveTab(active));
+    let tabs_entity = container.id();
+    let mut tabs = Tabs { headers: Vec::new(), pages: Vec::new() };
+    container = container.with_children(|p| {
+        p.spawn(UiNode::default()).with_children(|p| {
+            for (index, label) in labels.iter().enumerate() {
+                let header = TabHeader { tabs: tabs_entity, index };
+                let bundle = if index == active { states.active.clone() } else { states.inactive.clone() };
+                let entity = p.spawn((bundle, states.clone(), header)).with_children(|p| {
+                    text(label.clone(), (), style.clone(), p);
+                }).id();
+                tabs.headers.push(entity);
+            }
+        });
+        for index in 0..labels.len() {
+            let mut page = UiNode::default();
+            page.node.flex_direction = FlexDirection::Column;
+            page.node.flex_grow = 1.0;
+            if index != active {
+                page.node.display = Display::None;
+            }
+            let entity = p.spawn(page).with_children(|p| children(p, index)).id();
+            tabs.pages.push(entity);
+        }
+    });
+    container.insert(tabs);
+    container.id()
+}
@@ -0,0 +1,84 @@
+use bevy_ecs::component::Tick;
+use bevy_ecs::prelude::*;
+use bevy_ecs::system::SystemChangeTick;
+use bevy_ui::widget::Text;
+use super::{AssetClass, TextStyle, UiChildBuilder, UiText};
+
+
+/// Formats the value a [`TextBinding`] is bound to, if it changed between two ticks.
+type TextSource = Box<dyn Fn(&World, Tick, Tick) -> Option<String> + Send + Sync>;
+
+/// Binds the text of a [`text_bound`] widget to a resource or component.
+/// Created with [`bind_resource`] or [`bind_component`].
+#[derive(Component)]
+pub struct TextBinding {
+    source: TextSource,
+}
+
+/// Creates a [`TextBinding`] that formats a resource, ie: `bind_resource(|score: &Score| format!("{}", score.0))`.
+pub fn bind_resource<R: Resource>(format: impl Fn(&R) -> String + Send + Sync + 'static) -> TextBinding {
+    TextBinding {
+        source: Box::new(move |world, last_run, this_run| {
+            let ticks = world.get_resource_change_ticks::<R>()?;
+            if !ticks.is_changed(last_run, this_run) { return None }
+            world.get_resource::<R>().map(&format)
+        }),
+    }
+}
+
+/// Creates a [`TextBinding`] that formats a component of the entity given, ie: `bind_component(player, |health: &Health| format!("{} HP", health.0))`.
+pub fn bind_component<C: Component>(entity: Entity, format: impl Fn(&C) -> String + Send + Sync + 'static) -> TextBinding {
+    TextBinding {
+        source: Box::new(move |world, last_run, this_run| {
+            let entity = world.get_entity(entity).ok()?;
+            let ticks = entity.get_change_ticks::<C>()?;
+            if !ticks.is_changed(last_run, this_run) { return None }
+            entity.get::<C>().map(&format)
+        }),
+    }
+}
+
+/// Updates the text of [`text_bound`] widgets whose resource or component changed since the last run,
+/// or that were just spawned.
+/// Only reads the world, so that it can run alongside other systems that do not write to it.
+pub fn update_bound_texts(
+    world: &World,
+    bindings: Query<(Entity, Ref<TextBinding>, &Text)>,
+    ticks: SystemChangeTick,
+    mut commands: Commands,
+) {
+    for (entity, binding, text) in &bindings {
+        let last_run = if binding.is_added() { Tick::new(0) } else { ticks.last_run() };
+        let Some(value) = (binding.source)(world, last_run, ticks.this_run()) else { continue };
+        if text.0 != value {
+            commands.entity(entity).insert(Text(value));
+        }
+    }
+}
+
+/// Spawns a [`UiText`] whose text is kept up to date with the resource or component it is bound to.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn text_bound(
+    binding: TextBinding,
+    class: impl AssetClass<UiText>,
+    text_class: impl AssetClass<TextStyle>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    text_boundi(binding, class, text_class, (), parent)
+}
+
+/// Spawns a [`UiText`] whose text is kept up to date with the resource or component it is bound to.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn text_boundi(
+    binding: TextBinding,
+    class: impl AssetClass<UiText>,
+    text_class: impl AssetClass<TextStyle>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    let mut bundle = UiText::default();
+    class.apply(parent.assets(), &mut bundle);
+    let mut style = TextStyle::default();
+    text_class.apply(parent.assets(), &mut style);
+    parent.spawn((bundle, Text::default(), style.into_bundle(), binding, extras)).id()
+}
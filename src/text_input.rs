@@ -0,0 +1,415 @@
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+use bevy_input::ButtonInput;
+use bevy_input::keyboard::{Key, KeyCode, KeyboardInput};
+use bevy_input::mouse::MouseButton;
+use bevy_text::{TextColor, TextSpan};
+use bevy_ui::Interaction;
+use bevy_ui::widget::Text;
+use super::{AssetClass, Disabled, TextStyle, UiButton, UiChildBuilder, UiText};
+
+
+/// Entity that receives keyboard input, if any.
+/// Set when a [`text_input`] is pressed, and cleared when anything else is pressed or Escape is hit.
+#[derive(Resource, Copy, Clone, Eq, PartialEq, Debug, Default)]
+pub struct UiFocus(pub Option<Entity>);
+
+/// Value and editing state of a [`text_input`].
+/// Positions are counted in characters, not bytes.
+/// The value can be set directly, ie: cleared after it is submitted. The caret and selection are kept within it.
+#[derive(Component, Clone, Debug)]
+pub struct TextInput {
+    pub value: String,
+    pub placeholder: String,
+    /// Maximum number of characters the value may hold.
+    pub max_length: Option<usize>,
+    /// Color of selected text.
+    pub selection_color: Color,
+    caret: usize,
+    anchor: Option<usize>,
+}
+
+impl TextInput {
+    pub fn new(value: impl Into<String>, placeholder: impl Into<String>, max_length: Option<usize>) -> Self {
+        let mut value: String = value.into();
+        if let Some(max_length) = max_length {
+            value = value.chars().take(max_length).collect();
+        }
+        Self {
+            caret: value.chars().count(),
+            value,
+            placeholder: placeholder.into(),
+            max_length,
+            selection_color: Color::srgb(0.39, 0.58, 0.93),
+            anchor: None,
+        }
+    }
+
+    /// Position of the caret.
+    pub fn caret(&self) -> usize {
+        self.caret.min(self.len())
+    }
+
+    /// Start and end of the selected characters, if any are selected.
+    pub fn selection(&self) -> Option<(usize, usize)> {
+        let anchor = self.anchor?.min(self.len());
+        let caret = self.caret();
+        match anchor.cmp(&caret) {
+            std::cmp::Ordering::Less => Some((anchor, caret)),
+            std::cmp::Ordering::Greater => Some((caret, anchor)),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    /// Selects the entire value.
+    pub fn select_all(&mut self) {
+        self.anchor = Some(0);
+        self.caret = self.len();
+    }
+
+    /// Moves the caret to a position, extending the selection if `select` is true.
+    pub fn move_caret(&mut self, position: usize, select: bool) {
+        self.clamp();
+        if select {
+            self.anchor.get_or_insert(self.caret);
+        }
+        else {
+            self.anchor = None;
+        }
+        self.caret = position.min(self.len());
+    }
+
+    /// Replaces the selection, or inserts at the caret, with as much of the string as fits within the maximum length.
+    pub fn insert(&mut self, string: &str) {
+        self.clamp();
+        self.delete_selection();
+        let room = self.max_length.map(|max| max.saturating_sub(self.len())).unwrap_or(usize::MAX);
+        let string: String = string.chars().filter(|c| !c.is_control()).take(room).collect();
+        let index = self.byte_index(self.caret);
+        self.value.insert_str(index, &string);
+        self.caret += string.chars().count();
+    }
+
+    /// Deletes the selection, or the character before the caret.
+    pub fn backspace(&mut self) {
+        self.clamp();
+        if !self.delete_selection() && self.caret > 0 {
+            self.caret -= 1;
+            let index = self.byte_index(self.caret);
+            self.value.remove(index);
+        }
+    }
+
+    /// Deletes the selection, or the character after the caret.
+    pub fn delete(&mut self) {
+        self.clamp();
+        if !self.delete_selection() && self.caret < self.len() {
+            let index = self.byte_index(self.caret);
+            self.value.remove(index);
+        }
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        let selection = self.selection();
+        self.anchor = None;
+        let Some((start, end)) = selection else { return false };
+        let range = self.byte_index(start)..self.byte_index(end);
+        self.value.replace_range(range, "");
+        self.caret = start;
+        true
+    }
+
+    /// Keeps the caret and anchor within the value, which may have been shortened since they were set.
+    fn clamp(&mut self) {
+        let len = self.len();
+        self.caret = self.caret.min(len);
+        self.anchor = self.anchor.map(|anchor| anchor.min(len));
+    }
+
+    fn len(&self) -> usize {
+        self.value.chars().count()
+    }
+
+    fn byte_index(&self, position: usize) -> usize {
+        self.value.char_indices().nth(position).map(|(i, _)| i).unwrap_or(self.value.len())
+    }
+}
+
+/// Text spans a [`text_input`] renders its value with.
+#[derive(Component, Copy, Clone, Debug)]
+pub struct TextInputSpans {
+    before: Entity,
+    selected: Entity,
+    caret: Entity,
+    after: Entity,
+    placeholder: Entity,
+}
+
+/// Sent when the value of a [`text_input`] is edited.
+#[derive(Event, Clone, Eq, PartialEq, Debug)]
+pub struct TextInputChanged {
+    pub entity: Entity,
+    pub value: String,
+}
+
+/// Sent when Enter is hit while a [`text_input`] is focused.
+#[derive(Event, Clone, Eq, PartialEq, Debug)]
+pub struct TextInputSubmitted {
+    pub entity: Entity,
+    pub value: String,
+}
+
+/// Focuses text inputs when they are pressed, and clears focus when anything else is pressed.
+#[allow(clippy::type_complexity)]
+pub fn focus_text_inputs(
+    mouse: Res<ButtonInput<MouseButton>>,
+    inputs: Query<(Entity, &Interaction), (With<TextInput>, Without<Disabled>)>,
+    mut focus: ResMut<UiFocus>,
+) {
+    if !mouse.just_pressed(MouseButton::Left) { return }
+    let pressed = inputs
+        .iter()
+        .find(|(_, interaction)| **interaction == Interaction::Pressed)
+        .map(|(entity, _)| entity);
+    focus.set_if_neq(UiFocus(pressed));
+}
+
+/// Edits the focused text input from keyboard input.
+/// Escape unfocuses it and is consumed, so that it does not also close the [`modal`](crate::modal) the input is in.
+pub fn edit_text_inputs(
+    mut keys: EventReader<KeyboardInput>,
+    mut held: ResMut<ButtonInput<KeyCode>>,
+    mut focus: ResMut<UiFocus>,
+    mut inputs: Query<&mut TextInput, Without<Disabled>>,
+    mut changed: EventWriter<TextInputChanged>,
+    mut submitted: EventWriter<TextInputSubmitted>,
+) {
+    let Some(entity) = focus.0 else {
+        keys.clear();
+        return;
+    };
+    let Ok(mut input) = inputs.get_mut(entity) else {
+        keys.clear();
+        return;
+    };
+    let shift = held.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let control = held.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight, KeyCode::SuperLeft, KeyCode::SuperRight]);
+    let before = input.value.clone();
+    for key in keys.read() {
+        if !key.state.is_pressed() { continue }
+        match &key.logical_key {
+            Key::Character(c) if control && c.eq_ignore_ascii_case("a") => input.select_all(),
+            Key::Character(_) if control => {}
+            Key::Character(c) => input.insert(c),
+            Key::Space => input.insert(" "),
+            Key::Backspace => input.backspace(),
+            Key::Delete => input.delete(),
+            Key::ArrowLeft => {
+                let caret = match (input.selection(), shift) {
+                    (Some((start, _)), false) => start,
+                    _ => input.caret().saturating_sub(1),
+                };
+                input.move_caret(caret, shift);
+            }
+            Key::ArrowRight => {
+                let caret = match (input.selection(), shift) {
+                    (Some((_, end)), false) => end,
+                    _ => input.caret() + 1,
+                };
+                input.move_caret(caret, shift);
+            }
+            Key::Home => input.move_caret(0, shift),
+            Key::End => input.move_caret(usize::MAX, shift),
+            Key::Enter => {
+                submitted.send(TextInputSubmitted { entity, value: input.value.clone() });
+            }
+            Key::Escape => {
+                focus.0 = None;
+                held.clear_just_pressed(KeyCode::Escape);
+            }
+            _ => {}
+        }
+    }
+    if input.value != before {
+        changed.send(TextInputChanged { entity, value: input.value.clone() });
+    }
+}
+
+/// Updates the spans of text inputs whose value or focus changed.
+pub fn render_text_inputs(
+    focus: Res<UiFocus>,
+    inputs: Query<(Entity, Ref<TextInput>, &TextInputSpans)>,
+    mut spans: Query<(&mut TextSpan, &mut TextColor)>,
+) {
+    for (entity, input, input_spans) in &inputs {
+        if !input.is_changed() && !focus.is_changed() { continue }
+        let focused = focus.0 == Some(entity);
+        let chars: Vec<char> = input.value.chars().collect();
+        let (start, end) = input.selection().unwrap_or((input.caret(), input.caret()));
+        let caret = if focused && start == end { "|" } else { "" };
+        let placeholder = if chars.is_empty() && !focused { input.placeholder.as_str() } else { "" };
+        let contents = [
+            (input_spans.before, chars[..start].iter().collect()),
+            (input_spans.selected, chars[start..end].iter().collect()),
+            (input_spans.caret, caret.to_string()),
+            (input_spans.after, chars[end..].iter().collect()),
+            (input_spans.placeholder, placeholder.to_string()),
+        ];
+        for (span, content) in contents {
+            let Ok((mut span, _)) = spans.get_mut(span) else { continue };
+            if span.0 != content {
+                span.0 = content;
+            }
+        }
+        if let Ok((_, mut color)) = spans.get_mut(input_spans.selected) {
+            color.0 = input.selection_color;
+        }
+    }
+}
+
+/// Spawns a [`UiButton`] containing editable text.
+/// Focused when pressed. The placeholder is shown while the value is empty and the input is not focused.
+/// Placeholder style is applied on top of the text style.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn text_input(
+    value: impl Into<String>,
+    placeholder: impl Into<String>,
+    max_length: Option<usize>,
+    class: impl AssetClass<UiButton>,
+    text_style: impl AssetClass<TextStyle>,
+    placeholder_style: impl AssetClass<TextStyle>,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    text_inputi(value, placeholder, max_length, class, text_style, placeholder_style, (), parent)
+}
+
+/// Spawns a [`UiButton`] containing editable text.
+/// Focused when pressed. The placeholder is shown while the value is empty and the input is not focused.
+/// Placeholder style is applied on top of the text style.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+#[allow(clippy::too_many_arguments)]
+pub fn text_inputi(
+    value: impl Into<String>,
+    placeholder: impl Into<String>,
+    max_length: Option<usize>,
+    class: impl AssetClass<UiButton>,
+    text_style: impl AssetClass<TextStyle>,
+    placeholder_style: impl AssetClass<TextStyle>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder
+) -> Entity {
+    let mut bundle = UiButton::default();
+    class.apply(parent.assets(), &mut bundle);
+    let mut style = TextStyle::default();
+    text_style.apply(parent.assets(), &mut style);
+    let mut placeholder_text_style = style.clone();
+    placeholder_style.apply(parent.assets(), &mut placeholder_text_style);
+
+    let mut spans = None;
+    let mut input = parent.spawn((bundle, extras));
+    input.insert_state(TextInput::new(value, placeholder, max_length));
+    let mut input = input.with_children(|p| {
+        p.spawn((UiText::default(), Text::default(), style.clone().into_bundle())).with_children(|p| {
+            let mut span = |style: &TextStyle| p.spawn((TextSpan::default(), style.clone().into_bundle())).id();
+            spans = Some(TextInputSpans {
+                before: span(&style),
+                selected: span(&style),
+                caret: span(&style),
+                after: span(&style),
+                placeholder: span(&placeholder_text_style),
+            });
+        });
+    });
+    if let Some(spans) = spans {
+        input.insert(spans);
+    }
+    input.id()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TextInput;
+
+    #[test]
+    fn insert_at_caret() {
+        let mut input = TextInput::new("hllo", "", None);
+        input.move_caret(1, false);
+        input.insert("e");
+        assert_eq!(input.value, "hello");
+        assert_eq!(input.caret(), 2);
+    }
+
+    #[test]
+    fn insert_skips_control_characters() {
+        let mut input = TextInput::new("", "", None);
+        input.insert("a\tb\n");
+        assert_eq!(input.value, "ab");
+    }
+
+    #[test]
+    fn backspace_and_delete() {
+        let mut input = TextInput::new("héllo", "", None);
+        input.move_caret(2, false);
+        input.backspace();
+        assert_eq!(input.value, "hllo");
+        assert_eq!(input.caret(), 1);
+        input.delete();
+        assert_eq!(input.value, "hlo");
+        input.move_caret(0, false);
+        input.backspace();
+        input.move_caret(usize::MAX, false);
+        input.delete();
+        assert_eq!(input.value, "hlo");
+    }
+
+    #[test]
+    fn selection_is_replaced() {
+        let mut input = TextInput::new("hello world", "", None);
+        input.move_caret(6, false);
+        input.move_caret(usize::MAX, true);
+        assert_eq!(input.selection(), Some((6, 11)));
+        input.insert("there");
+        assert_eq!(input.value, "hello there");
+        assert_eq!(input.selection(), None);
+        input.select_all();
+        input.backspace();
+        assert_eq!(input.value, "");
+    }
+
+    #[test]
+    fn selection_toward_start() {
+        let mut input = TextInput::new("hello", "", None);
+        input.move_caret(1, true);
+        assert_eq!(input.selection(), Some((1, 5)));
+        input.delete();
+        assert_eq!(input.value, "h");
+    }
+
+    #[test]
+    fn max_length() {
+        let mut input = TextInput::new("abcdef", "", Some(4));
+        assert_eq!(input.value, "abcd");
+        input.insert("xyz");
+        assert_eq!(input.value, "abcd");
+        input.move_caret(1, false);
+        input.move_caret(3, true);
+        input.insert("xyz");
+        assert_eq!(input.value, "axyd");
+    }
+
+    #[test]
+    fn value_shortened_from_outside() {
+        let mut input = TextInput::new("hello", "", None);
+        input.move_caret(2, true);
+        input.value.clear();
+        assert_eq!(input.caret(), 0);
+        assert_eq!(input.selection(), None);
+        input.insert("hi");
+        assert_eq!(input.value, "hi");
+        assert_eq!(input.caret(), 2);
+        input.value = "h".into();
+        input.backspace();
+        input.delete();
+        assert_eq!(input.value, "");
+    }
+}
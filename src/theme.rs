@@ -0,0 +1,329 @@
+use bevy_asset::{AssetServer, Handle};
+use bevy_color::Color;
+use bevy_ecs::prelude::*;
+use bevy_ecs::world::EntityRef;
+use bevy_hierarchy::Children;
+use bevy_text::{Font, TextColor, TextFont};
+use bevy_ui::Val;
+use bevy_ui::widget::Button;
+use super::{TextStyle, UiButton, UiImage, UiNode, UiText};
+
+
+/// Design tokens shared by [`ThemeClass`]es, so that swapping the resource restyles every [`Themed`] widget.
+/// Scales are ordered from smallest to largest, and looked up by step, ie: `theme.space(2)`.
+#[derive(Resource, Clone, Debug)]
+pub struct Theme {
+    pub palette: Palette,
+    /// Spacing scale in logical pixels, for margins, paddings and gaps.
+    pub spacing: Vec<f32>,
+    /// Font size scale.
+    pub font_sizes: Vec<f32>,
+    /// Corner radius scale in logical pixels.
+    pub radii: Vec<f32>,
+    pub font: Handle<Font>,
+    pub bold_font: Handle<Font>,
+}
+
+/// Colors of a [`Theme`].
+#[derive(Copy, Clone, Debug)]
+pub struct Palette {
+    /// Color behind everything else, ie: that of the root.
+    pub background: Color,
+    /// Color of panels and cards on top of the background.
+    pub surface: Color,
+    pub primary: Color,
+    /// Color of text and icons on top of the primary color.
+    pub on_primary: Color,
+    pub secondary: Color,
+    pub text: Color,
+    pub text_muted: Color,
+    pub border: Color,
+    pub error: Color,
+}
+
+impl Theme {
+    pub fn dark() -> Self {
+        Self::with_palette(Palette {
+            background: Color::srgb_u8(18, 18, 24),
+            surface: Color::srgb_u8(36, 36, 46),
+            primary: Color::srgb_u8(66, 135, 245),
+            on_primary: Color::WHITE,
+            secondary: Color::srgb_u8(57, 179, 118),
+            text: Color::srgb_u8(236, 236, 240),
+            text_muted: Color::srgb_u8(150, 150, 162),
+            border: Color::srgb_u8(70, 70, 84),
+            error: Color::srgb_u8(232, 84, 84),
+        })
+    }
+
+    pub fn light() -> Self {
+        Self::with_palette(Palette {
+            background: Color::srgb_u8(244, 244, 248),
+            surface: Color::WHITE,
+            primary: Color::srgb_u8(40, 100, 220),
+            on_primary: Color::WHITE,
+            secondary: Color::srgb_u8(36, 140, 90),
+            text: Color::srgb_u8(24, 24, 30),
+            text_muted: Color::srgb_u8(100, 100, 112),
+            border: Color::srgb_u8(204, 204, 214),
+            error: Color::srgb_u8(200, 40, 40),
+        })
+    }
+
+    /// Black and white with saturated accents, and larger text.
+    pub fn high_contrast() -> Self {
+        Self {
+            font_sizes: vec![16.0, 20.0, 24.0, 32.0, 40.0],
+            ..Self::with_palette(Palette {
+                background: Color::BLACK,
+                surface: Color::BLACK,
+                primary: Color::srgb_u8(255, 255, 0),
+                on_primary: Color::BLACK,
+                secondary: Color::srgb_u8(0, 255, 255),
+                text: Color::WHITE,
+                text_muted: Color::WHITE,
+                border: Color::WHITE,
+                error: Color::srgb_u8(255, 80, 80),
+            })
+        }
+    }
+
+    /// Theme with the palette given, and the scales and fonts shared by the built-in themes.
+    fn with_palette(palette: Palette) -> Self {
+        Self {
+            palette,
+            spacing: vec![0.0, 4.0, 8.0, 12.0, 16.0, 24.0, 32.0],
+            font_sizes: vec![12.0, 14.0, 16.0, 20.0, 28.0],
+            radii: vec![0.0, 4.0, 8.0, 16.0],
+            font: Handle::default(),
+            bold_font: Handle::default(),
+        }
+    }
+
+    /// Step of the spacing scale, clamped to the largest.
+    pub fn space(&self, step: usize) -> Val {
+        Val::Px(Self::step(&self.spacing, step))
+    }
+
+    /// Step of the corner radius scale, clamped to the largest.
+    pub fn radius(&self, step: usize) -> Val {
+        Val::Px(Self::step(&self.radii, step))
+    }
+
+    /// Step of the font size scale, clamped to the largest.
+    pub fn font_size(&self, step: usize) -> f32 {
+        Self::step(&self.font_sizes, step)
+    }
+
+    fn step(scale: &[f32], step: usize) -> f32 {
+        scale.get(step).or(scale.last()).copied().unwrap_or_default()
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// Something that can overwrite a value, typically one of the bundles in this crate such as [`UiNode`].
+/// Depends on a [`Theme`] and an [`AssetServer`], unlike [`AssetClass`](crate::AssetClass).
+/// Given to widgets with [`Themed`], so that it is applied again whenever the theme changes.
+pub trait ThemeClass<B> {
+    fn apply(self, theme: &Theme, assets: &AssetServer, b: &mut B);
+}
+
+impl<T> ThemeClass<T> for () {
+    fn apply(self, _t: &Theme, _a: &AssetServer, _b: &mut T) {}
+}
+
+impl<F, B> ThemeClass<B> for F
+where
+    F: FnOnce(&Theme, &AssetServer, &mut B),
+{
+    fn apply(self, t: &Theme, a: &AssetServer, b: &mut B) {
+        self(t, a, b);
+    }
+}
+
+macro_rules! impl_theme_class_tuple {
+    ($($F:ident $i:tt),*) => {
+        impl<$($F,)* B> ThemeClass<B> for ($($F,)*)
+        where
+            $($F: ThemeClass<B>,)*
+        {
+            fn apply(self, t: &Theme, a: &AssetServer, b: &mut B) {
+                $(self.$i.apply(t, a, b);)*
+            }
+        }
+    };
+}
+
+all_tuples!(impl_theme_class_tuple);
+
+impl<C: ThemeClass<B>, B> ThemeClass<B> for Option<C> {
+    fn apply(self, t: &Theme, a: &AssetServer, b: &mut B) {
+        if let Some(class) = self {
+            class.apply(t, a, b);
+        }
+    }
+}
+
+type BoxedThemeClass<B> = Box<dyn Fn(&Theme, &AssetServer, &mut B) + Send + Sync>;
+
+/// Applies a [`ThemeClass`] to a widget, on top of the classes it was spawned with, and again whenever the [`Theme`] changes.
+/// Given to widgets as extras, ie: `nodei(c_half, Themed::new(c_surface), p, |p| ...)`.
+/// Themed [`TextStyle`]s given to a widget without text of its own, such as a [`text_button`](crate::text_button), apply to the texts among its children.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+#[derive(Component)]
+pub struct Themed<B: ThemedBundle>(BoxedThemeClass<B>);
+
+impl<B: ThemedBundle> Themed<B> {
+    pub fn new(class: impl ThemeClass<B> + Clone + Send + Sync + 'static) -> Self {
+        Self(Box::new(move |theme, assets, b| class.clone().apply(theme, assets, b)))
+    }
+}
+
+/// Bundle that can be read back from the components of a widget, so that [`Themed`] classes can be applied to it again.
+pub trait ThemedBundle: Sized + Send + Sync + 'static {
+    /// Reads the bundle from the components of an entity, if it has them.
+    fn read(entity: EntityRef) -> Option<Self>;
+    /// Inserts the bundle into an entity.
+    fn write(self, world: &mut World, entity: Entity);
+    /// Entities a [`Themed`] class of this bundle applies to.
+    fn targets(world: &World, entity: Entity) -> Vec<Entity> {
+        let _ = world;
+        vec![entity]
+    }
+}
+
+fn get<C: Component + Clone + Default>(entity: &EntityRef) -> C {
+    entity.get::<C>().cloned().unwrap_or_default()
+}
+
+impl ThemedBundle for UiNode {
+    fn read(entity: EntityRef) -> Option<Self> {
+        Some(Self {
+            node: entity.get().cloned()?,
+            background_color: get(&entity),
+            border_color: get(&entity),
+            border_radius: get(&entity),
+            focus_policy: get(&entity),
+            visibility: get(&entity),
+            z_index: get(&entity),
+        })
+    }
+
+    fn write(self, world: &mut World, entity: Entity) {
+        world.entity_mut(entity).insert(self);
+    }
+}
+
+impl ThemedBundle for UiText {
+    fn read(entity: EntityRef) -> Option<Self> {
+        Some(Self {
+            node: entity.get().cloned()?,
+            layout: get(&entity),
+            background_color: get(&entity),
+            focus_policy: get(&entity),
+            visibility: get(&entity),
+            z_index: get(&entity),
+        })
+    }
+
+    fn write(self, world: &mut World, entity: Entity) {
+        world.entity_mut(entity).insert(self);
+    }
+}
+
+impl ThemedBundle for UiButton {
+    fn read(entity: EntityRef) -> Option<Self> {
+        Some(Self {
+            button: entity.get().cloned()?,
+            node: entity.get().cloned()?,
+            image: get(&entity),
+            background_color: get(&entity),
+            border_color: get(&entity),
+            border_radius: get(&entity),
+            focus_policy: get(&entity),
+            visibility: get(&entity),
+            z_index: get(&entity),
+        })
+    }
+
+    fn write(self, world: &mut World, entity: Entity) {
+        world.entity_mut(entity).insert(self);
+    }
+}
+
+impl ThemedBundle for UiImage {
+    fn read(entity: EntityRef) -> Option<Self> {
+        Some(Self {
+            node: entity.get().cloned()?,
+            image: get(&entity),
+            background_color: get(&entity),
+            border_radius: get(&entity),
+            focus_policy: get(&entity),
+            visibility: get(&entity),
+            z_index: get(&entity),
+        })
+    }
+
+    fn write(self, world: &mut World, entity: Entity) {
+        world.entity_mut(entity).insert(self);
+    }
+}
+
+impl ThemedBundle for TextStyle {
+    fn read(entity: EntityRef) -> Option<Self> {
+        let font = entity.get::<TextFont>()?;
+        Some(Self {
+            font: font.font.clone(),
+            font_size: font.font_size,
+            font_smoothing: font.font_smoothing,
+            color: get::<TextColor>(&entity).0,
+        })
+    }
+
+    fn write(self, world: &mut World, entity: Entity) {
+        world.entity_mut(entity).insert(self.into_bundle());
+    }
+
+    fn targets(world: &World, entity: Entity) -> Vec<Entity> {
+        match world.entity(entity).contains::<TextFont>() {
+            true => vec![entity],
+            false => world.get::<Children>(entity).map(|children| children.to_vec()).unwrap_or_default(),
+        }
+    }
+}
+
+/// Applies [`Themed`] classes to widgets that were just given them, or to every widget when the [`Theme`] changes.
+/// Also applies them again to buttons whose [`UiButton`] was re-inserted, such as by [`apply_button_states`](crate::apply_button_states),
+/// so that the colors of a state do not replace those of the theme.
+pub fn apply_themes(world: &mut World) {
+    let Some(theme) = world.get_resource::<Theme>().cloned() else { return };
+    let restyle_all = world.is_resource_changed::<Theme>();
+    let assets = world.resource::<AssetServer>().clone();
+    restyle::<UiNode>(world, &theme, &assets, restyle_all);
+    restyle::<UiText>(world, &theme, &assets, restyle_all);
+    restyle::<UiButton>(world, &theme, &assets, restyle_all);
+    restyle::<UiImage>(world, &theme, &assets, restyle_all);
+    restyle::<TextStyle>(world, &theme, &assets, restyle_all);
+}
+
+fn restyle<B: ThemedBundle>(world: &mut World, theme: &Theme, assets: &AssetServer, restyle_all: bool) {
+    let entities: Vec<Entity> = match restyle_all {
+        true => world.query_filtered::<Entity, With<Themed<B>>>().iter(world).collect(),
+        false => world
+            .query_filtered::<Entity, (With<Themed<B>>, Or<(Changed<Themed<B>>, Changed<Button>)>)>()
+            .iter(world)
+            .collect(),
+    };
+    for entity in entities {
+        for target in B::targets(world, entity) {
+            let Some(mut b) = B::read(world.entity(target)) else { continue };
+            (world.get::<Themed<B>>(entity).unwrap().0)(theme, assets, &mut b);
+            b.write(world, target);
+        }
+    }
+}
@@ -0,0 +1,143 @@
+use bevy_asset::AssetServer;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy_render::view::Visibility;
+use bevy_time::Time;
+use bevy_ui::{ComputedNode, FocusPolicy, GlobalZIndex, Interaction, Node, PositionType, UiScale, Val};
+use bevy_window::{PrimaryWindow, Window};
+use super::{AssetClass, Class, TextStyle, UiChildBuilder, UiNode};
+
+
+/// [`GlobalZIndex`] of tooltips, above modals.
+pub const TOOLTIP_Z_INDEX: i32 = 3000;
+
+/// Default seconds an entity must be hovered before its tooltip shows.
+pub const TOOLTIP_DELAY: f32 = 0.5;
+
+/// Distance between the cursor and the top left corner of a tooltip, in UI units, ie: logical pixels scaled by [`UiScale`].
+pub const TOOLTIP_CURSOR_OFFSET: f32 = 16.0;
+
+/// Callback that spawns the contents of a [`Tooltip`].
+pub type TooltipSpawner = Box<dyn FnMut(&mut UiChildBuilder) + Send + Sync>;
+
+/// Tooltip shown near the cursor while an entity is hovered.
+/// Created with [`tooltip`] or [`tooltip_with`], and attached to any entity, usually as the extras of an inline widget.
+#[derive(Component)]
+#[require(Interaction)]
+pub struct Tooltip {
+    /// Seconds the entity must be hovered before the tooltip shows.
+    pub delay: f32,
+    node: UiNode,
+    spawn_contents: TooltipSpawner,
+    hovered_for: f32,
+    popup: Option<Entity>,
+}
+
+impl Tooltip {
+    /// Node holding the contents of the tooltip, while it is showing.
+    pub fn popup(&self) -> Option<Entity> {
+        self.popup
+    }
+}
+
+/// Marks the node of a showing [`Tooltip`].
+#[derive(Component, Copy, Clone, Eq, PartialEq, Debug)]
+pub struct TooltipPopup {
+    /// Entity the tooltip is attached to.
+    pub target: Entity,
+}
+
+/// Creates a [`Tooltip`] that shows text.
+/// Handy as the extras of inline widgets, ie: `imagei(c_slot, tooltip("Sword", TOOLTIP_DELAY, c_tooltip, c_pixel), p)`.
+pub fn tooltip(
+    text: impl Into<String>,
+    delay: f32,
+    class: impl Class<UiNode>,
+    text_style: impl AssetClass<TextStyle> + Clone + Send + Sync + 'static
+) -> Tooltip {
+    let text = text.into();
+    tooltip_with(delay, class, move |p| {
+        crate::text(text.clone(), (), text_style.clone(), p);
+    })
+}
+
+/// Creates a [`Tooltip`] whose contents are spawned by the callback function argument each time it shows.
+pub fn tooltip_with(
+    delay: f32,
+    class: impl Class<UiNode>,
+    children: impl FnMut(&mut UiChildBuilder) + Send + Sync + 'static
+) -> Tooltip {
+    let mut node = UiNode::default();
+    class.apply(&mut node);
+    node.node.position_type = PositionType::Absolute;
+    node.focus_policy = FocusPolicy::Pass;
+    Tooltip {
+        delay,
+        node,
+        spawn_contents: Box::new(children),
+        hovered_for: 0.0,
+        popup: None,
+    }
+}
+
+/// Shows tooltips once their entity has been hovered long enough, and hides them when it no longer is.
+pub fn show_tooltips(
+    time: Res<Time>,
+    mut tooltips: Query<(Entity, &mut Tooltip, &Interaction)>,
+    popups: Query<(Entity, &TooltipPopup)>,
+    assets: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    for (entity, mut tooltip, interaction) in &mut tooltips {
+        if *interaction != Interaction::Hovered {
+            tooltip.hovered_for = 0.0;
+            if let Some(popup) = tooltip.popup.take() {
+                commands.entity(popup).despawn_recursive();
+            }
+            continue;
+        }
+        tooltip.hovered_for += time.delta_secs();
+        if tooltip.popup.is_some() || tooltip.hovered_for < tooltip.delay { continue }
+        let tooltip = &mut *tooltip;
+        let mut node = tooltip.node.clone();
+        node.visibility = Visibility::Hidden;
+        let spawn_contents = &mut tooltip.spawn_contents;
+        let popup = commands
+            .spawn((node, GlobalZIndex(TOOLTIP_Z_INDEX), TooltipPopup { target: entity }))
+            .with_children(|builder| spawn_contents(&mut UiChildBuilder::new(builder, &assets)))
+            .id();
+        tooltip.popup = Some(popup);
+    }
+
+    // Cleans up tooltips whose entity was despawned while they were showing.
+    for (popup, TooltipPopup { target }) in &popups {
+        if !tooltips.contains(*target) {
+            commands.entity(popup).despawn_recursive();
+        }
+    }
+}
+
+/// Moves showing tooltips next to the cursor, keeping them within the window.
+pub fn position_tooltips(
+    windows: Query<&Window, With<PrimaryWindow>>,
+    ui_scale: Option<Res<UiScale>>,
+    mut popups: Query<(&mut Node, &mut Visibility, &ComputedNode), With<TooltipPopup>>,
+) {
+    let Ok(window) = windows.get_single() else { return };
+    let Some(cursor) = window.cursor_position() else { return };
+    // Window positions are in logical pixels, whereas Val::Px is scaled by UiScale.
+    let scale = ui_scale.map_or(1.0, |ui_scale| ui_scale.0);
+    let cursor = cursor / scale;
+    let (width, height) = (window.width() / scale, window.height() / scale);
+    for (mut node, mut visibility, computed) in &mut popups {
+        let size = computed.size() * computed.inverse_scale_factor();
+        let left = (cursor.x + TOOLTIP_CURSOR_OFFSET).min(width - size.x).max(0.0);
+        let top = (cursor.y + TOOLTIP_CURSOR_OFFSET).min(height - size.y).max(0.0);
+        if node.left != Val::Px(left) { node.left = Val::Px(left); }
+        if node.top != Val::Px(top) { node.top = Val::Px(top); }
+        // Popups stay hidden until they have been laid out, so that they never show at the wrong size.
+        if size.x > 0.0 {
+            visibility.set_if_neq(Visibility::Inherited);
+        }
+    }
+}
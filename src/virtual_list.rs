@@ -0,0 +1,214 @@
+use std::ops::Range;
+use bevy_asset::AssetServer;
+use bevy_ecs::prelude::*;
+use bevy_hierarchy::{BuildChildren, DespawnRecursiveExt};
+use bevy_ui::{ComputedNode, Display, Node, PositionType, ScrollPosition, Val};
+use super::{Class, ScrollView, UiChildBuilder, UiEntityCommands, UiNode, scroll_content, spawn_scroll_view};
+
+
+/// Callback that spawns the contents of a row of a [`virtual_list`].
+pub type RowSpawner = Box<dyn FnMut(&mut UiChildBuilder, usize) + Send + Sync>;
+
+/// State of a [`virtual_list`], stored alongside its [`ScrollView`].
+/// Only rows within the viewport have entities. Row entities are reused as the list scrolls,
+/// with their contents respawned by the row callback.
+#[derive(Component)]
+pub struct VirtualList {
+    item_count: usize,
+    row_height: f32,
+    spawn_row: RowSpawner,
+    rows: Vec<(Option<usize>, Entity)>,
+    visible: Range<usize>,
+    dirty: bool,
+}
+
+impl VirtualList {
+    pub fn item_count(&self) -> usize {
+        self.item_count
+    }
+
+    /// Height of each row in logical pixels.
+    pub fn row_height(&self) -> f32 {
+        self.row_height
+    }
+
+    /// Changes the number of rows, and respawns the contents of the visible ones.
+    pub fn set_item_count(&mut self, item_count: usize) {
+        self.item_count = item_count;
+        self.dirty = true;
+    }
+
+    /// Respawns the contents of the visible rows.
+    /// Useful when the data the rows display changes.
+    pub fn refresh(&mut self) {
+        self.dirty = true;
+    }
+
+    /// Inserts this list, taking over the rows of the one it replaces when it is rebuilt.
+    /// Their contents are respawned, since the row callback or item count may have changed.
+    fn replace(mut self, entity: Entity, world: &mut World) {
+        let Ok(mut entity) = world.get_entity_mut(entity) else { return };
+        if let Some(mut old) = entity.get_mut::<VirtualList>() {
+            self.rows = std::mem::take(&mut old.rows);
+            self.visible = old.visible.clone();
+            self.dirty = true;
+        }
+        entity.insert(self);
+    }
+
+    /// Rows that should be visible given a scroll offset and viewport height, in logical pixels.
+    fn rows_within(&self, offset: f32, height: f32) -> Range<usize> {
+        if self.row_height <= 0.0 {
+            return 0..0;
+        }
+        let first = (offset / self.row_height).floor().max(0.0) as usize;
+        let last = ((offset + height) / self.row_height).ceil().max(0.0) as usize;
+        first.min(self.item_count)..last.min(self.item_count)
+    }
+}
+
+/// Assigns row entities to the rows visible in each virtual list, spawning more rows when needed.
+pub fn update_virtual_lists(
+    mut lists: Query<(&mut VirtualList, &ScrollView)>,
+    viewports: Query<(&ScrollPosition, &ComputedNode)>,
+    mut nodes: Query<&mut Node>,
+    assets: Res<AssetServer>,
+    mut commands: Commands,
+) {
+    for (mut list, view) in &mut lists {
+        let Ok((scroll, viewport)) = viewports.get(view.viewport) else { continue };
+        let height = viewport.size().y * viewport.inverse_scale_factor();
+        let visible = list.rows_within(scroll.offset_y, height);
+        if visible == list.visible && !list.dirty { continue }
+        let list = &mut *list;
+        if list.dirty {
+            if let Ok(mut content) = nodes.get_mut(view.content) {
+                content.height = Val::Px(list.item_count as f32 * list.row_height);
+            }
+        }
+
+        // Frees rows that scrolled out of view, or all of them if the list is dirty.
+        for (index, _) in &mut list.rows {
+            if index.is_some_and(|index| list.dirty || !visible.contains(&index)) {
+                *index = None;
+            }
+        }
+
+        // Fills in the rows that scrolled into view, reusing freed row entities first.
+        for index in visible.clone() {
+            if list.rows.iter().any(|(row_index, _)| *row_index == Some(index)) { continue }
+            let row = match list.rows.iter_mut().find(|(row_index, _)| row_index.is_none()) {
+                Some(row) => {
+                    row.0 = Some(index);
+                    row.1
+                }
+                None => {
+                    let row = commands.spawn(row_node(list.row_height)).id();
+                    commands.entity(view.content).add_child(row);
+                    list.rows.push((Some(index), row));
+                    row
+                }
+            };
+            let mut node = row_node(list.row_height).node;
+            node.top = Val::Px(index as f32 * list.row_height);
+            commands.entity(row).despawn_descendants().insert(node);
+            let spawn_row = &mut list.spawn_row;
+            commands.entity(row).with_children(|builder| {
+                spawn_row(&mut UiChildBuilder::new(builder, &assets), index);
+            });
+        }
+
+        // Hides rows that were not reused.
+        for (index, row) in &list.rows {
+            if index.is_none() {
+                if let Ok(mut node) = nodes.get_mut(*row) {
+                    node.display = Display::None;
+                }
+            }
+        }
+        list.visible = visible;
+        list.dirty = false;
+    }
+}
+
+fn row_node(row_height: f32) -> UiNode {
+    let mut row = UiNode::default();
+    row.node.position_type = PositionType::Absolute;
+    row.node.width = Val::Percent(100.0);
+    row.node.height = Val::Px(row_height);
+    row
+}
+
+/// Spawns a scrolling list of rows of a fixed height, where only the rows within view are spawned.
+/// The callback spawns the contents of the row at the index given, and is called again whenever a row scrolls into view.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn virtual_list(
+    item_count: usize,
+    row_height: f32,
+    class: impl Class<UiNode>,
+    parent: &mut UiChildBuilder,
+    spawn_row: impl FnMut(&mut UiChildBuilder, usize) + Send + Sync + 'static
+) -> Entity {
+    virtual_listi(item_count, row_height, class, (), parent, spawn_row)
+}
+
+/// Spawns a scrolling list of rows of a fixed height, where only the rows within view are spawned.
+/// The callback spawns the contents of the row at the index given, and is called again whenever a row scrolls into view.
+/// Requires [`UiDslPlugin`](crate::UiDslPlugin).
+pub fn virtual_listi(
+    item_count: usize,
+    row_height: f32,
+    class: impl Class<UiNode>,
+    extras: impl Bundle,
+    parent: &mut UiChildBuilder,
+    spawn_row: impl FnMut(&mut UiChildBuilder, usize) + Send + Sync + 'static
+) -> Entity {
+    let mut content = scroll_content();
+    content.node.height = Val::Px(item_count as f32 * row_height);
+    let list = VirtualList {
+        item_count,
+        row_height,
+        spawn_row: Box::new(spawn_row),
+        rows: Vec::new(),
+        visible: 0..0,
+        dirty: false,
+    };
+    let configure = move |container: &mut UiEntityCommands| {
+        container.queue(move |entity: Entity, world: &mut World| list.replace(entity, world));
+    };
+    spawn_scroll_view(class, content, None::<((), ())>, extras, configure, parent, |_| {})
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VirtualList;
+
+    fn list(item_count: usize, row_height: f32) -> VirtualList {
+        VirtualList {
+            item_count,
+            row_height,
+            spawn_row: Box::new(|_, _| {}),
+            rows: Vec::new(),
+            visible: 0..0,
+            dirty: false,
+        }
+    }
+
+    #[test]
+    fn rows_within_viewport() {
+        let list = list(100, 20.0);
+        assert_eq!(list.rows_within(0.0, 100.0), 0..5);
+        assert_eq!(list.rows_within(30.0, 100.0), 1..7);
+    }
+
+    #[test]
+    fn rows_within_item_count() {
+        assert_eq!(list(3, 20.0).rows_within(0.0, 100.0), 0..3);
+        assert_eq!(list(3, 20.0).rows_within(200.0, 100.0), 3..3);
+    }
+
+    #[test]
+    fn no_rows_without_height() {
+        assert_eq!(list(100, 0.0).rows_within(0.0, 100.0), 0..0);
+    }
+}
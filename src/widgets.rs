@@ -1,17 +1,16 @@
 use bevy_asset::AssetServer;
 use bevy_ecs::prelude::Bundle;
-use bevy_text::{TextStyle, TextSection};
 use bevy_ecs::entity::Entity;
 use bevy_ecs::system::Commands;
-use bevy_ui::{Val, FlexWrap, Style, JustifyContent, AlignItems};
-use bevy_ui::node_bundles::{NodeBundle, TextBundle, ButtonBundle, ImageBundle};
+use bevy_ui::{Val, FlexWrap, Node, JustifyContent, AlignItems};
+use bevy_ui::widget::Text;
 use bevy_hierarchy::BuildChildren;
-use super::{Class, AssetClass, UiChildBuilder};
+use super::{Class, AssetClass, UiChildBuilder, UiNode, UiText, UiButton, UiImage, TextStyle};
 
 
-/// Spawns a [`NodeBundle`] as the root with children.
+/// Spawns a [`UiNode`] as the root with children.
 pub fn root(
-    class: impl Class<NodeBundle>,
+    class: impl Class<UiNode>,
     assets: &AssetServer,
     commands: &mut Commands,
     children: impl FnOnce(&mut UiChildBuilder)
@@ -19,33 +18,30 @@ pub fn root(
     rooti(class, assets, commands, (), children)
 }
 
-/// Spawns a [`NodeBundle`] as the root with children.
+/// Spawns a [`UiNode`] as the root with children.
 pub fn rooti(
-    class: impl Class<NodeBundle>,
+    class: impl Class<UiNode>,
     assets: &AssetServer,
     commands: &mut Commands,
     extras: impl Bundle,
     children: impl FnOnce(&mut UiChildBuilder)
 ) -> Entity {
-    let mut bundle = NodeBundle::default();
+    let mut bundle = UiNode::default();
     class.apply(&mut bundle);
     commands
         .spawn((bundle, extras))
         .with_children(|builder| {
-            children(&mut UiChildBuilder {
-                builder,
-                assets
-            });
+            children(&mut UiChildBuilder::new(builder, assets));
         })
         .id()
 }
 
 
-/// Spawns a clear [`NodeBundle`] that takes up the full space of its parent.
+/// Spawns a clear [`UiNode`] that takes up the full space of its parent.
 /// Often required for embedding other widgets after the initial widget is spawned.
 pub fn blank(
     parent: Entity,
-    class: impl Class<NodeBundle>,
+    class: impl Class<UiNode>,
     assets: &AssetServer,
     commands: &mut Commands,
     children: impl FnOnce(&mut UiChildBuilder)
@@ -53,11 +49,11 @@ pub fn blank(
     blanki(parent, class, assets, commands, (), children)
 }
 
-/// Spawns a clear [`NodeBundle`] that takes up the full space of its parent.
+/// Spawns a clear [`UiNode`] that takes up the full space of its parent.
 /// Often required for embedding other widgets after the initial widget is spawned.
 pub fn blanki(
     parent: Entity,
-    class: impl Class<NodeBundle>,
+    class: impl Class<UiNode>,
     assets: &AssetServer,
     commands: &mut Commands,
     extras: impl Bundle,
@@ -66,17 +62,17 @@ pub fn blanki(
     commands
         .entity(parent)
         .with_children(|builder| {
-            let mut bundle = NodeBundle::default();
+            let mut bundle = UiNode::default();
             class.apply(&mut bundle);
-            let mut builder = UiChildBuilder { builder, assets };
+            let mut builder = UiChildBuilder::new(builder, assets);
             builder.spawn((bundle, extras)).with_children(children);
         })
         .id()
 }
 
-/// Spawns a [`NodeBundle`] with children.
+/// Spawns a [`UiNode`] with children.
 pub fn node(
-    class: impl Class<NodeBundle>,
+    class: impl Class<UiNode>,
     parent: &mut UiChildBuilder,
     children: impl FnOnce(&mut UiChildBuilder)
 ) -> Entity {
@@ -84,14 +80,14 @@ pub fn node(
 }
 
 
-/// Spawns a [`NodeBundle`] with children.
+/// Spawns a [`UiNode`] with children.
 pub fn nodei(
-    class: impl Class<NodeBundle>,
+    class: impl Class<UiNode>,
     extras: impl Bundle,
     parent: &mut UiChildBuilder,
     children: impl FnOnce(&mut UiChildBuilder)
 ) -> Entity {
-    let mut bundle = NodeBundle::default();
+    let mut bundle = UiNode::default();
     class.apply(&mut bundle);
     
     let mut commands = parent.spawn(bundle);
@@ -99,92 +95,87 @@ pub fn nodei(
     commands.with_children(children).id()
 }
 
-/// Spawns a [`TextBundle`].
+/// Spawns a [`UiText`].
 pub fn text(
     text: impl Into<String>,
-    class: impl AssetClass<TextBundle>,
+    class: impl AssetClass<UiText>,
     text_class: impl AssetClass<TextStyle>,
     parent: &mut UiChildBuilder
 ) -> Entity {
     texti(text, class, text_class, (), parent)
 }
 
-/// Spawns a [`TextBundle`].
+/// Spawns a [`UiText`].
 pub fn texti(
     text: impl Into<String>,
-    class: impl AssetClass<TextBundle>,
+    class: impl AssetClass<UiText>,
     text_class: impl AssetClass<TextStyle>,
     extras: impl Bundle,
     parent: &mut UiChildBuilder
 ) -> Entity {
-    let mut bundle = TextBundle::default();
+    let mut bundle = UiText::default();
     class.apply(parent.assets, &mut bundle);
-    let sections = &mut bundle.text.sections;
     let mut style = TextStyle::default();
     text_class.apply(parent.assets, &mut style);
-    sections.push(TextSection {
-        value: text.into(),
-        style,
-    });
-    parent.spawn((bundle, extras)).id()
+    parent.spawn((bundle, Text(text.into()), style.into_bundle(), extras)).id()
 }
 
-/// Spawns a [`ButtonBundle`] with children.
+/// Spawns a [`UiButton`] with children.
 pub fn button(
-    class: impl AssetClass<ButtonBundle>,
+    class: impl AssetClass<UiButton>,
     parent: &mut UiChildBuilder,
     children: impl FnOnce(&mut UiChildBuilder)
 ) -> Entity {
     buttoni(class, (), parent, children)
 }
 
-/// Spawns a [`ButtonBundle`] with children.
+/// Spawns a [`UiButton`] with children.
 pub fn buttoni(
-    class: impl AssetClass<ButtonBundle>,
+    class: impl AssetClass<UiButton>,
     extras: impl Bundle,
     parent: &mut UiChildBuilder,
     children: impl FnOnce(&mut UiChildBuilder)
 ) -> Entity {
-    let mut bundle = ButtonBundle::default();
+    let mut bundle = UiButton::default();
     class.apply(parent.assets, &mut bundle);
     parent
         .spawn((bundle, extras))
         .with_children(children).id()
 }
 
-/// Spawns a [`ButtonBundle`] without children.
+/// Spawns a [`UiButton`] without children.
 pub fn simple_button(
-    class: impl AssetClass<ButtonBundle>,
+    class: impl AssetClass<UiButton>,
     parent: &mut UiChildBuilder
 ) -> Entity {
     simple_buttoni(class, (), parent)
 }
 
-/// Spawns a [`ButtonBundle`] without children.
+/// Spawns a [`UiButton`] without children.
 pub fn simple_buttoni(
-    class: impl AssetClass<ButtonBundle>,
+    class: impl AssetClass<UiButton>,
     extras: impl Bundle,
     parent: &mut UiChildBuilder
 ) -> Entity {
-    let mut bundle = ButtonBundle::default();
+    let mut bundle = UiButton::default();
     class.apply(parent.assets, &mut bundle);
     parent.spawn((bundle, extras)).id()
 }
 
-/// Spawns a [`ButtonBundle`] with a single [`TextBundle`] as its child.
+/// Spawns a [`UiButton`] with a single [`UiText`] as its child.
 pub fn text_button(
     txt: impl Into<String>,
-    class: impl AssetClass<ButtonBundle>,
+    class: impl AssetClass<UiButton>,
     text_style: impl AssetClass<TextStyle>,
     parent: &mut UiChildBuilder
 ) -> Entity {
     text_buttoni(txt, class, text_style, (), parent)
 }
 
-/// Spawns a [`ButtonBundle`] with a single [`TextBundle`] as its child.
+/// Spawns a [`UiButton`] with a single [`UiText`] as its child.
 pub fn text_buttoni(
     txt: impl Into<String>,
-    class: impl AssetClass<ButtonBundle>,
+    class: impl AssetClass<UiButton>,
     text_style: impl AssetClass<TextStyle>,
     extras: impl Bundle,
     parent: &mut UiChildBuilder
@@ -194,79 +185,79 @@ pub fn text_buttoni(
     })
 }
 
-/// Spawns an [`ImageBundle`].
+/// Spawns an [`UiImage`].
 pub fn image(
-    class: impl AssetClass<ImageBundle>,
+    class: impl AssetClass<UiImage>,
     parent: &mut UiChildBuilder
 ) -> Entity {
     imagei(class, (), parent)
 }
 
-/// Spawns an [`ImageBundle`].
+/// Spawns an [`UiImage`].
 pub fn imagei(
-    class: impl AssetClass<ImageBundle>,
+    class: impl AssetClass<UiImage>,
     extras: impl Bundle,
     parent: &mut UiChildBuilder
 ) -> Entity {
-    let mut bundle = ImageBundle::default();
+    let mut bundle = UiImage::default();
     class.apply(parent.assets, &mut bundle);
     parent.spawn((bundle, extras)).id()
 }
 
-/// Spawns an [`ImageBundle`] with children.
+/// Spawns an [`UiImage`] with children.
 pub fn image_pane(
-    class: impl AssetClass<ImageBundle>,
+    class: impl AssetClass<UiImage>,
     parent: &mut UiChildBuilder,
     children: impl FnOnce(&mut UiChildBuilder)
 ) -> Entity {
     image_panei(class, parent, (), children)
 }
 
-/// Spawns an [`ImageBundle`] with children.
+/// Spawns an [`UiImage`] with children.
 pub fn image_panei(
-    class: impl AssetClass<ImageBundle>,
+    class: impl AssetClass<UiImage>,
     parent: &mut UiChildBuilder,
     extras: impl Bundle,
     children: impl FnOnce(&mut UiChildBuilder)
 ) -> Entity {
-    let mut bundle = ImageBundle::default();
+    let mut bundle = UiImage::default();
     class.apply(parent.assets, &mut bundle);
     parent
         .spawn((bundle, extras))
         .with_children(children).id()
 }
 
-/// Spawns a [`NodeBundle`] composed of [`NodeBundle`] cells in the form of a grid.
+/// Spawns a [`UiNode`] composed of [`UiNode`] cells in the form of a grid.
 /// The callback function argument spawns the contents of those cells.
 pub fn grid(
     rows: usize,
     columns: usize,
-    class: impl Class<NodeBundle>,
+    class: impl Class<UiNode>,
     parent: &mut UiChildBuilder,
     children: impl FnMut(&mut UiChildBuilder, usize, usize)
 ) -> Entity {
     gridi(rows, columns, class, (), parent, children)
 }
 
-/// Spawns a [`NodeBundle`] composed of [`NodeBundle`] cells in the form of a grid.
+/// Spawns a [`UiNode`] composed of [`UiNode`] cells in the form of a grid.
 /// The callback function argument spawns the contents of those cells.
 pub fn gridi(
     rows: usize,
     columns: usize,
-    class: impl Class<NodeBundle>,
+    class: impl Class<UiNode>,
     extras: impl Bundle,
     parent: &mut UiChildBuilder,
     mut children: impl FnMut(&mut UiChildBuilder, usize, usize)
 ) -> Entity {
     // Spawns container
-    let mut container_bundle = NodeBundle::default();
+    let mut container_bundle = UiNode::default();
     class.apply(&mut container_bundle);
-    container_bundle.style.flex_wrap = FlexWrap::Wrap;
+    container_bundle.node.flex_wrap = FlexWrap::Wrap;
     let mut container = parent.spawn((container_bundle, extras));
 
     // Spawns cells as children of the container
-    let cell_bundle = NodeBundle {
-        style: Style {
+    let cell_bundle = UiNode {
+        node: Node {
             width: Val::Percent(100.0 / columns as f32),
             height: Val::Percent(100.0 / rows as f32),
             justify_content: JustifyContent::Center,
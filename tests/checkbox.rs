@@ -0,0 +1,60 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+fn c_checked(_a: &AssetServer, b: &mut UiButton) {
+    b.background_color = Color::WHITE.into();
+}
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        checkbox(false, (), c_checked, p);
+        toggle(true, (), (), (), p);
+    });
+}
+
+fn press(app: &mut App, entity: Entity) {
+    *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::Pressed;
+    app.update();
+    *app.world_mut().get_mut::<Interaction>(entity).unwrap() = Interaction::None;
+    app.update();
+}
+
+fn changes(app: &mut App) -> Vec<CheckedChanged> {
+    app.world_mut().resource_mut::<Events<CheckedChanged>>().drain().collect()
+}
+
+#[test]
+fn pressing_flips_checked() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let checkbox = app.world_mut().query_filtered::<Entity, (With<Checked>, Without<Children>)>().single(app.world());
+    let toggle = app.world_mut().query_filtered::<Entity, (With<Checked>, With<Children>)>().single(app.world());
+
+    press(&mut app, checkbox);
+    assert_eq!(app.world().get::<Checked>(checkbox), Some(&Checked(true)));
+    assert_eq!(app.world().get::<BackgroundColor>(checkbox), Some(&BackgroundColor(Color::WHITE)));
+    assert_eq!(changes(&mut app), [CheckedChanged { entity: checkbox, checked: true }]);
+
+    press(&mut app, toggle);
+    assert_eq!(app.world().get::<Checked>(toggle), Some(&Checked(false)));
+    assert_eq!(app.world().get::<Node>(toggle).unwrap().justify_content, JustifyContent::FlexStart);
+    assert_eq!(changes(&mut app), [CheckedChanged { entity: toggle, checked: false }]);
+}
+
+#[test]
+fn disabled_checkboxes_ignore_presses() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let checkbox = app.world_mut().query_filtered::<Entity, (With<Checked>, Without<Children>)>().single(app.world());
+    app.world_mut().entity_mut(checkbox).insert(Disabled);
+
+    press(&mut app, checkbox);
+    assert_eq!(app.world().get::<Checked>(checkbox), Some(&Checked(false)));
+    assert!(changes(&mut app).is_empty());
+}
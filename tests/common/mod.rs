@@ -0,0 +1,11 @@
+#![allow(dead_code)]
+use bevy::prelude::*;
+use bevy_ui_dsl::*;
+
+/// App with the plugins the widgets of this crate need, without a window.
+pub fn app() -> App {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default(), bevy::input::InputPlugin, UiDslPlugin));
+    app.init_asset::<Image>().init_asset::<Font>();
+    app
+}
@@ -0,0 +1,72 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::input::ButtonState;
+use bevy::input::mouse::MouseButtonInput;
+use bevy_ui_dsl::*;
+
+mod common;
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        dropdown(["Low", "Medium", "High"], 1, (), (), (), (), p);
+    });
+}
+
+/// Clicks an entity, as the mouse and the UI focus system would.
+fn click(app: &mut App, entity: Entity) {
+    for (state, interaction) in [(ButtonState::Pressed, Interaction::Pressed), (ButtonState::Released, Interaction::None)] {
+        app.world_mut().send_event(MouseButtonInput { button: MouseButton::Left, state, window: Entity::PLACEHOLDER });
+        *app.world_mut().get_mut::<Interaction>(entity).unwrap() = interaction;
+        app.update();
+    }
+}
+
+fn dropdown_state(app: &mut App) -> (Entity, Dropdown) {
+    let (entity, dropdown) = app.world_mut().query::<(Entity, &Dropdown)>().single(app.world());
+    (entity, dropdown.clone())
+}
+
+fn option(app: &mut App, index: usize) -> Entity {
+    app.world_mut().query::<(Entity, &DropdownOption)>().iter(app.world())
+        .find_map(|(entity, option)| (option.index == index).then_some(entity))
+        .unwrap()
+}
+
+#[test]
+fn picking_an_option_selects_it_and_closes_the_list() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let (entity, dropdown) = dropdown_state(&mut app);
+    assert_eq!(app.world().get::<Node>(dropdown.list).unwrap().display, Display::None);
+
+    click(&mut app, dropdown.header);
+    assert!(dropdown_state(&mut app).1.open);
+    assert_eq!(app.world().get::<Node>(dropdown.list).unwrap().display, Display::Flex);
+
+    let high = option(&mut app, 2);
+    click(&mut app, high);
+    let (_, dropdown) = dropdown_state(&mut app);
+    assert_eq!((dropdown.selected, dropdown.open), (2, false));
+    assert_eq!(app.world().get::<Node>(dropdown.list).unwrap().display, Display::None);
+    let changes: Vec<_> = app.world_mut().resource_mut::<Events<DropdownChanged>>().drain().collect();
+    assert_eq!(changes, [DropdownChanged { entity, selected: 2 }]);
+
+    let label = app.world().get::<Children>(dropdown.header).unwrap()[0];
+    assert_eq!(app.world().get::<Text>(label).unwrap().0, "High");
+}
+
+#[test]
+fn pressing_elsewhere_closes_the_list() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let (_, dropdown) = dropdown_state(&mut app);
+    click(&mut app, dropdown.header);
+
+    app.world_mut().send_event(MouseButtonInput { button: MouseButton::Left, state: ButtonState::Pressed, window: Entity::PLACEHOLDER });
+    app.update();
+    let dropdown = dropdown_state(&mut app).1;
+    assert_eq!((dropdown.selected, dropdown.open), (1, false));
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "layout")]
+use bevy::prelude::*;
+use bevy_ui_dsl::*;
+
+mod common;
+
+#[derive(Component)]
+struct Marker;
+
+#[test]
+fn modified_layout_keeps_grid_cells() {
+    let mut app = common::app();
+    let layout: UiLayout = ron::from_str(r#"(
+        children: [
+            Grid(rows: 2, columns: 2, cells: [[Text(text: "a")], [Text(text: "b")], [Text(text: "c")], [Text(text: "d")]]),
+        ],
+    )"#).unwrap();
+    let handle = app.world_mut().resource_mut::<Assets<UiLayout>>().add(layout);
+    let root = layout_asset_root(handle.clone(), &mut app.world_mut().commands());
+    app.update();
+
+    let grid = app.world().get::<Children>(root).unwrap()[0];
+    let cells = app.world().get::<Children>(grid).unwrap().to_vec();
+    assert_eq!(cells.len(), 4);
+    for &cell in &cells {
+        app.world_mut().entity_mut(cell).insert(Marker);
+    }
+
+    app.world_mut().resource_mut::<Assets<UiLayout>>().get_mut(&handle).unwrap();
+    app.update();
+    app.update();
+
+    let grid = app.world().get::<Children>(root).unwrap()[0];
+    assert_eq!(app.world().get::<Children>(grid).unwrap().to_vec(), cells);
+    assert!(cells.iter().all(|&cell| app.world().entity(cell).contains::<Marker>()));
+}
+
+#[test]
+fn grid_cell_count_must_match() {
+    let layout: UiLayout = ron::from_str(r#"(
+        children: [
+            Node(children: [Grid(rows: 2, columns: 2, cells: [[], [], []])]),
+        ],
+    )"#).unwrap();
+    let err = layout.validate(&ClassRegistry::default()).unwrap_err();
+    assert_eq!(err, LayoutError::CellCount { path: "root > Node #0 > Grid #0".into(), expected: 4, found: 3 });
+
+    let layout: UiLayout = ron::from_str("(children: [Grid(rows: 2, columns: 2)])").unwrap();
+    assert_eq!(layout.validate(&ClassRegistry::default()), Ok(()));
+}
@@ -0,0 +1,63 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::legacy::{ButtonBundle, ImageBundle, NodeBundle, TextBundle, legacy};
+use bevy_ui_dsl::*;
+
+// Classes as written for Bevy 0.14, unchanged apart from their imports
+fn c_half(b: &mut NodeBundle) {
+    let s = &mut b.style;
+    s.width = Val::Percent(50.);
+    s.height = Val::Percent(100.);
+    s.flex_direction = FlexDirection::Column;
+    s.justify_content = JustifyContent::Center;
+    s.align_items = AlignItems::Center;
+    s.padding = UiRect::all(Val::Px(10.));
+}
+
+fn c_green(b: &mut NodeBundle) {
+    b.background_color = Color::srgb_u8(125, 212, 148).into();
+}
+
+fn c_text(_a: &AssetServer, b: &mut TextBundle) {
+    b.style.margin = UiRect::all(Val::Px(10.));
+}
+
+fn c_button_left(assets: &AssetServer, b: &mut ButtonBundle) {
+    let s = &mut b.style;
+    s.width = Val::Px(64.);
+    s.height = Val::Px(24.);
+    b.image = assets.load("button.png").into();
+    b.image.color = Color::srgb_u8(66, 135, 245)
+}
+
+fn c_inv_slot(assets: &AssetServer, b: &mut ImageBundle) {
+    b.style.width = Val::Px(32.);
+    b.image = assets.load("item_slot.png").into();
+}
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root(legacy(c_half), &assets, &mut commands, |p| {
+        node((legacy(c_half), legacy(c_green)), p, |_p| {});
+        text("Hiya", legacy(c_text), (), p);
+        text_button("Left", legacy(c_button_left), (), p);
+        image(legacy(c_inv_slot), p);
+    });
+}
+
+#[test]
+fn classes_written_for_bevy_0_14() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.init_asset::<Image>().init_asset::<Font>();
+    app.world_mut().run_system_once(spawn).unwrap();
+
+    let mut nodes = app.world_mut().query::<&Node>();
+    let widths: Vec<Val> = nodes.iter(app.world()).map(|node| node.width).collect();
+    assert!(widths.contains(&Val::Percent(50.)));
+    assert!(widths.contains(&Val::Px(64.)));
+    assert!(widths.contains(&Val::Px(32.)));
+
+    let mut images = app.world_mut().query::<&ImageNode>();
+    let colors: Vec<Color> = images.iter(app.world()).map(|image| image.color).collect();
+    assert!(colors.contains(&Color::srgb_u8(66, 135, 245)));
+}
@@ -0,0 +1,60 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy_ui_dsl::*;
+
+mod common;
+
+#[derive(Resource)]
+struct Spawned {
+    modal: Entity,
+    input: Entity,
+}
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    let mut input = Entity::PLACEHOLDER;
+    let modal = modal(MODAL_Z_INDEX, (), (), &assets, &mut commands, |p| {
+        input = text_input("", "Name", None, (), (), (), p);
+    });
+    commands.insert_resource(Spawned { modal, input });
+}
+
+fn app() -> App {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    app
+}
+
+fn send_escape(app: &mut App, state: ButtonState) {
+    let window = app.world_mut().spawn_empty().id();
+    app.world_mut().send_event(KeyboardInput {
+        key_code: KeyCode::Escape,
+        logical_key: Key::Escape,
+        state,
+        repeat: false,
+        window,
+    });
+    app.update();
+}
+
+fn press_escape(app: &mut App) {
+    send_escape(app, ButtonState::Pressed);
+    send_escape(app, ButtonState::Released);
+}
+
+#[test]
+fn escape_unfocuses_input_before_closing_modal() {
+    let mut app = app();
+    let Spawned { modal, input } = *app.world().resource::<Spawned>();
+    app.world_mut().resource_mut::<UiFocus>().0 = Some(input);
+
+    press_escape(&mut app);
+    assert_eq!(app.world().resource::<UiFocus>().0, None);
+    assert!(app.world().get_entity(modal).is_ok());
+
+    press_escape(&mut app);
+    assert!(app.world().get_entity(modal).is_err());
+}
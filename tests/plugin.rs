@@ -0,0 +1,43 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+#[derive(Resource, Default)]
+struct SeenChecked(Vec<bool>);
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        checkbox(false, (), (), p);
+    });
+}
+
+fn record(checkboxes: Query<&Checked>, mut seen: ResMut<SeenChecked>) {
+    seen.0.extend(checkboxes.iter().map(|checked| checked.0));
+}
+
+#[test]
+fn systems_ordered_after_input_see_updated_widget_state() {
+    let mut app = common::app();
+    app.init_resource::<SeenChecked>();
+    app.add_systems(Update, record.after(UiDslSystems::Input).before(UiDslSystems::Callbacks));
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+
+    let checkbox = app.world_mut().query_filtered::<Entity, With<Checked>>().single(app.world());
+    *app.world_mut().get_mut::<Interaction>(checkbox).unwrap() = Interaction::Pressed;
+    app.update();
+    assert_eq!(app.world().resource::<SeenChecked>().0, [false, true]);
+}
+
+#[test]
+fn widgets_spawn_without_the_plugin() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.init_asset::<Image>().init_asset::<Font>();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    assert_eq!(app.world_mut().query::<&Checked>().single(app.world()), &Checked(false));
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "plugin")]
+use std::time::Duration;
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy::time::TimeUpdateStrategy;
+use bevy_ui_dsl::*;
+
+mod common;
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        labeled_progress_bar(0.0, ProgressDirection::LeftToRight, (), (), (), p);
+    });
+}
+
+fn app() -> (App, Entity) {
+    let mut app = common::app();
+    app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_millis(50)));
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let bar = app.world_mut().query_filtered::<Entity, With<ProgressBar>>().single(app.world());
+    (app, bar)
+}
+
+fn shown(app: &App, bar: Entity) -> f32 {
+    app.world().get::<ProgressBar>(bar).unwrap().shown()
+}
+
+#[test]
+fn fill_animates_toward_progress() {
+    let (mut app, bar) = app();
+    app.world_mut().get_mut::<Progress>(bar).unwrap().0 = 1.0;
+    app.update();
+    let first = shown(&app, bar);
+    assert!(first > 0.0 && first < 1.0, "{first}");
+    app.update();
+    assert!(shown(&app, bar) > first);
+
+    for _ in 0..100 {
+        app.update();
+    }
+    assert_eq!(shown(&app, bar), 1.0);
+    let fill = app.world().get::<ProgressBar>(bar).unwrap().fills[0];
+    assert_eq!(app.world().get::<Node>(fill).unwrap().width, Val::Percent(100.0));
+}
+
+#[test]
+fn labels_show_the_target_progress() {
+    let (mut app, bar) = app();
+    app.world_mut().get_mut::<ProgressBar>(bar).unwrap().smoothing = 0.0;
+    app.world_mut().get_mut::<Progress>(bar).unwrap().0 = 0.5;
+    app.update();
+    assert_eq!(shown(&app, bar), 0.5);
+    let label = app.world().get::<ProgressBar>(bar).unwrap().label.unwrap();
+    assert_eq!(app.world().get::<Text>(label).unwrap().0, "50%");
+}
@@ -0,0 +1,53 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+fn c_selected(_a: &AssetServer, b: &mut UiButton) {
+    b.background_color = Color::WHITE.into();
+}
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        radio_group(["Easy", "Normal", "Hard"], 1, (), (), c_selected, (), p);
+    });
+}
+
+fn app() -> (App, Entity, RadioGroup) {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let (entity, group) = app.world_mut().query::<(Entity, &RadioGroup)>().single(app.world());
+    let group = group.clone();
+    (app, entity, group)
+}
+
+fn selected(app: &App, group: &RadioGroup) -> Vec<bool> {
+    let selected = BackgroundColor(Color::WHITE);
+    group.buttons.iter().map(|button| app.world().get::<BackgroundColor>(*button) == Some(&selected)).collect()
+}
+
+#[test]
+fn only_one_button_is_selected() {
+    let (mut app, entity, group) = app();
+    assert_eq!(selected(&app, &group), [false, true, false]);
+
+    *app.world_mut().get_mut::<Interaction>(group.buttons[2]).unwrap() = Interaction::Pressed;
+    app.update();
+    assert_eq!(app.world().get::<SelectedIndex>(entity), Some(&SelectedIndex(2)));
+    assert_eq!(selected(&app, &group), [false, false, true]);
+    let changes: Vec<_> = app.world_mut().resource_mut::<Events<RadioChanged>>().drain().collect();
+    assert_eq!(changes, [RadioChanged { entity, selected: 2 }]);
+}
+
+#[test]
+fn disabled_groups_ignore_presses() {
+    let (mut app, entity, group) = app();
+    app.world_mut().entity_mut(entity).insert(Disabled);
+    *app.world_mut().get_mut::<Interaction>(group.buttons[0]).unwrap() = Interaction::Pressed;
+    app.update();
+    assert_eq!(app.world().get::<SelectedIndex>(entity), Some(&SelectedIndex(1)));
+    assert!(app.world().resource::<Events<RadioChanged>>().is_empty());
+}
@@ -0,0 +1,100 @@
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+#[derive(Resource)]
+struct Root(Entity);
+
+fn layout() -> GridLayout {
+    GridLayout {
+        rows: vec![GridTrack::auto(); 2],
+        columns: vec![GridTrack::auto(); 2],
+        ..default()
+    }
+}
+
+fn children(p: &mut UiChildBuilder) {
+    grid(2, 3, (), p, |p, _row, _col| {
+        text("cell", (), (), p);
+    });
+    css_grid(&layout(), (), p, |p, _row, _col| {
+        text("cell", (), (), p);
+    });
+}
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    let root = root((), &assets, &mut commands, children);
+    commands.insert_resource(Root(root));
+}
+
+fn respawn(root: Res<Root>, assets: Res<AssetServer>, mut commands: Commands, tree: UiTree) {
+    rebuild(root.0, (), &assets, &mut commands, &tree, children);
+}
+
+/// Entities of the tree under the entity given, depth first.
+fn descendants(world: &World, entity: Entity) -> Vec<Entity> {
+    let mut entities = vec![entity];
+    for &child in world.get::<Children>(entity).into_iter().flatten() {
+        entities.extend(descendants(world, child));
+    }
+    entities
+}
+
+#[test]
+fn rebuild_keeps_every_cell() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.init_asset::<Font>();
+    app.world_mut().run_system_once(spawn).unwrap();
+    let root = app.world().resource::<Root>().0;
+    let before = descendants(app.world(), root);
+
+    app.world_mut().run_system_once(respawn).unwrap();
+    app.world_mut().run_system_once(respawn).unwrap();
+    let after = descendants(app.world(), root);
+
+    let children = app.world().get::<Children>(root).unwrap();
+    let (grid, css_grid) = (children[0], children[1]);
+    assert_eq!(app.world().get::<Children>(grid).unwrap().len(), 6);
+    assert_eq!(app.world().get::<Children>(css_grid).unwrap().len(), 4);
+    assert_eq!(before, after);
+}
+
+#[cfg(feature = "plugin")]
+fn stateful(p: &mut UiChildBuilder) {
+    checkbox(false, (), (), p);
+    text_input("", "Name", None, (), (), (), p);
+}
+
+#[cfg(feature = "plugin")]
+fn spawn_stateful(assets: Res<AssetServer>, mut commands: Commands) {
+    let root = root((), &assets, &mut commands, stateful);
+    commands.insert_resource(Root(root));
+}
+
+#[cfg(feature = "plugin")]
+fn respawn_stateful(root: Res<Root>, assets: Res<AssetServer>, mut commands: Commands, tree: UiTree) {
+    rebuild(root.0, (), &assets, &mut commands, &tree, stateful);
+}
+
+#[cfg(feature = "plugin")]
+#[test]
+fn rebuild_keeps_widget_state() {
+    let mut app = App::new();
+    app.add_plugins((MinimalPlugins, AssetPlugin::default()));
+    app.init_asset::<Font>();
+    app.world_mut().run_system_once(spawn_stateful).unwrap();
+    let root = app.world().resource::<Root>().0;
+    let children = app.world().get::<Children>(root).unwrap().to_vec();
+    let (checkbox, input) = (children[0], children[1]);
+    app.world_mut().get_mut::<Checked>(checkbox).unwrap().0 = true;
+    app.world_mut().get_mut::<TextInput>(input).unwrap().insert("Hiya");
+    let extra = app.world_mut().spawn(Node::default()).set_parent(root).id();
+
+    app.world_mut().run_system_once(respawn_stateful).unwrap();
+
+    assert_eq!(app.world().get::<Children>(root).unwrap().to_vec(), vec![checkbox, input, extra]);
+    assert!(app.world().get::<Checked>(checkbox).unwrap().0);
+    let input = app.world().get::<TextInput>(input).unwrap();
+    assert_eq!((input.value.as_str(), input.caret()), ("Hiya", 4));
+}
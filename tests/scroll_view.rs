@@ -0,0 +1,41 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+#[derive(Component)]
+struct Row;
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        scroll_view_bar((), (), (), p, |p| {
+            for _ in 0..3 {
+                nodei((), Row, p, |_p| {});
+            }
+        });
+    });
+}
+
+fn view(app: &mut App) -> ScrollView {
+    app.world_mut().query::<&ScrollView>().single(app.world()).clone()
+}
+
+#[test]
+fn children_are_spawned_in_a_scrolling_viewport() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let view = view(&mut app);
+
+    assert_eq!(app.world().get::<Node>(view.viewport).unwrap().overflow, Overflow::scroll_y());
+    assert_eq!(app.world().get::<Parent>(view.content).map(Parent::get), Some(view.viewport));
+    let rows = app.world_mut().query_filtered::<&Parent, With<Row>>().iter(app.world()).map(Parent::get).collect::<Vec<_>>();
+    assert_eq!(rows, [view.content; 3]);
+
+    let bar = view.scrollbar.unwrap();
+    assert_eq!(app.world().get::<Parent>(bar.thumb).map(Parent::get), Some(bar.track));
+    assert_eq!(app.world().get::<Node>(bar.thumb).unwrap().height, Val::Percent(100.0));
+}
+
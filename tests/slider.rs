@@ -0,0 +1,24 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        slider(0.3, 1.0..=0.0, 0.25, (), (), p);
+    });
+}
+
+#[test]
+fn sliders_snap_their_initial_value_within_a_flipped_range() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+
+    let (slider, value) = app.world_mut().query::<(&Slider, &SliderValue)>().single(app.world());
+    assert_eq!((slider.min, slider.max), (0.0, 1.0));
+    assert_eq!(value, &SliderValue(0.25));
+    assert!(app.world().get::<Node>(slider.thumb).is_some());
+}
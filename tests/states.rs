@@ -0,0 +1,49 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+fn c_hovered(_a: &AssetServer, b: &mut UiButton) {
+    b.background_color = Color::WHITE.into();
+}
+
+fn c_disabled(_a: &AssetServer, b: &mut UiButton) {
+    b.background_color = Color::srgb(0.5, 0.5, 0.5).into();
+}
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        state_button((), c_hovered, (), c_disabled, p, |_p| {});
+    });
+}
+
+fn background(app: &App, button: Entity) -> Color {
+    app.world().get::<BackgroundColor>(button).unwrap().0
+}
+
+#[test]
+fn buttons_follow_their_interaction_and_disabled_state() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let button = app.world_mut().query_filtered::<Entity, With<ButtonStates>>().single(app.world());
+    let normal = background(&app, button);
+
+    *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+    app.update();
+    assert_eq!(background(&app, button), Color::WHITE);
+
+    app.world_mut().entity_mut(button).insert(Disabled);
+    app.update();
+    assert_eq!(background(&app, button), Color::srgb(0.5, 0.5, 0.5));
+
+    app.world_mut().entity_mut(button).remove::<Disabled>();
+    app.update();
+    assert_eq!(background(&app, button), Color::WHITE);
+
+    *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::None;
+    app.update();
+    assert_eq!(background(&app, button), normal);
+}
@@ -0,0 +1,71 @@
+#![cfg(feature = "layout")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+#[derive(Component, Reflect)]
+struct Highlighted;
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        let panel = nodei((), UiClasses::new("panel"), p, |_p| {});
+        p.spawn(UiNode::default()).insert(PanelRef(panel));
+        state_button((), (), (), (), p, |_p| {});
+    });
+}
+
+#[derive(Component)]
+struct PanelRef(Entity);
+
+fn app() -> (App, Entity) {
+    let mut app = common::app();
+    app.register_type::<Highlighted>();
+    let sheet: StyleSheet = serde_json::from_str(r##"{ "rules": [
+        { "select": [{ "Class": "panel" }], "node": { "display": "Flex", "width": { "Px": 100.0 } } },
+        { "select": [{ "Component": "Highlighted" }], "background_color": "#ff0000" },
+        { "select": [{ "Widget": "Button" }], "border_color": "#00ff00" }
+    ] }"##).unwrap();
+    let handle = app.world_mut().resource_mut::<Assets<StyleSheet>>().add(sheet);
+    app.world_mut().resource_mut::<StyleSheets>().0.push(handle);
+    app.world_mut().run_system_once(spawn).unwrap();
+    // Asset events of the style sheet are only sent at the end of the first update
+    app.update();
+    app.update();
+    let panel = app.world_mut().query::<&PanelRef>().single(app.world()).0;
+    (app, panel)
+}
+
+#[test]
+fn widgets_keep_node_changes_made_after_styling() {
+    let (mut app, panel) = app();
+    assert_eq!(app.world().get::<Node>(panel).unwrap().width, Val::Px(100.0));
+
+    app.world_mut().get_mut::<Node>(panel).unwrap().display = Display::None;
+    app.update();
+    app.update();
+    assert_eq!(app.world().get::<Node>(panel).unwrap().display, Display::None);
+}
+
+#[test]
+fn inserted_markers_are_styled() {
+    let (mut app, panel) = app();
+    assert_eq!(app.world().get::<BackgroundColor>(panel).unwrap().0, Color::NONE);
+
+    app.world_mut().entity_mut(panel).insert(Highlighted);
+    app.update();
+    assert_eq!(app.world().get::<BackgroundColor>(panel).unwrap().0, Color::srgb(1.0, 0.0, 0.0));
+}
+
+#[test]
+fn button_rules_survive_state_changes() {
+    let (mut app, _) = app();
+    let button = app.world_mut().query_filtered::<Entity, With<ButtonStates>>().single(app.world());
+    let green = BorderColor(Color::srgb(0.0, 1.0, 0.0));
+    assert_eq!(app.world().get::<BorderColor>(button), Some(&green));
+
+    *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+    app.update();
+    assert_eq!(app.world().get::<BorderColor>(button), Some(&green));
+}
@@ -0,0 +1,55 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+fn c_active(_a: &AssetServer, b: &mut UiButton) {
+    b.background_color = Color::WHITE.into();
+}
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        tabs(["General", "Audio", "Video"], 0, (), (), c_active, (), p, |p, index| {
+            text(format!("Page {index}"), (), (), p);
+        });
+    });
+}
+
+fn displays(app: &App, tabs: &Tabs) -> Vec<Display> {
+    tabs.pages.iter().map(|page| app.world().get::<Node>(*page).unwrap().display).collect()
+}
+
+#[test]
+fn pressing_a_header_switches_pages() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let (entity, tabs) = app.world_mut().query::<(Entity, &Tabs)>().single(app.world());
+    let tabs = tabs.clone();
+    assert_eq!(displays(&app, &tabs), [Display::Flex, Display::None, Display::None]);
+
+    *app.world_mut().get_mut::<Interaction>(tabs.headers[2]).unwrap() = Interaction::Pressed;
+    app.update();
+    assert_eq!(app.world().get::<ActiveTab>(entity), Some(&ActiveTab(2)));
+    assert_eq!(displays(&app, &tabs), [Display::None, Display::None, Display::Flex]);
+    assert_eq!(app.world().get::<BackgroundColor>(tabs.headers[2]), Some(&BackgroundColor(Color::WHITE)));
+    assert_ne!(app.world().get::<BackgroundColor>(tabs.headers[0]), Some(&BackgroundColor(Color::WHITE)));
+    let changes: Vec<_> = app.world_mut().resource_mut::<Events<TabChanged>>().drain().collect();
+    assert_eq!(changes, [TabChanged { entity, active: 2 }]);
+}
+
+#[test]
+fn setting_the_active_tab_switches_pages() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let (entity, tabs) = app.world_mut().query::<(Entity, &Tabs)>().single(app.world());
+    let tabs = tabs.clone();
+
+    app.world_mut().entity_mut(entity).insert(ActiveTab(1));
+    app.update();
+    assert_eq!(displays(&app, &tabs), [Display::None, Display::Flex, Display::None]);
+    assert!(app.world().resource::<Events<TabChanged>>().is_empty());
+}
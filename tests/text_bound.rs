@@ -0,0 +1,45 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+#[derive(Resource, Default)]
+struct Score(u32);
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        text_bound(bind_resource(|score: &Score| format!("{}", score.0)), (), (), p);
+    });
+}
+
+fn text(app: &mut App) -> String {
+    app.world_mut().query_filtered::<&Text, With<TextBinding>>().single(app.world()).0.clone()
+}
+
+#[test]
+fn texts_follow_their_resource() {
+    let mut app = common::app();
+    app.init_resource::<Score>();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    assert_eq!(text(&mut app), "0");
+
+    app.world_mut().resource_mut::<Score>().0 = 5;
+    app.update();
+    assert_eq!(text(&mut app), "5");
+}
+
+#[test]
+fn texts_are_left_alone_while_the_resource_is_unchanged() {
+    let mut app = common::app();
+    app.init_resource::<Score>();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+
+    let entity = app.world_mut().query_filtered::<Entity, With<TextBinding>>().single(app.world());
+    app.world_mut().get_mut::<Text>(entity).unwrap().0 = "edited".into();
+    app.update();
+    assert_eq!(text(&mut app), "edited");
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+fn c_primary(theme: &Theme, _a: &AssetServer, b: &mut UiButton) {
+    b.background_color = theme.palette.primary.into();
+}
+
+fn c_hovered(_a: &AssetServer, b: &mut UiButton) {
+    b.border_color = Color::WHITE.into();
+}
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        state_buttoni((), c_hovered, (), (), Themed::new(c_primary), p, |_p| {});
+    });
+}
+
+#[test]
+fn button_states_keep_themed_colors() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+
+    let button = app.world_mut().query_filtered::<Entity, With<ButtonStates>>().single(app.world());
+    let primary = BackgroundColor(app.world().resource::<Theme>().palette.primary);
+    assert_eq!(app.world().get::<BackgroundColor>(button), Some(&primary));
+
+    *app.world_mut().get_mut::<Interaction>(button).unwrap() = Interaction::Hovered;
+    app.update();
+    assert_eq!(app.world().get::<BorderColor>(button), Some(&BorderColor(Color::WHITE)));
+    assert_eq!(app.world().get::<BackgroundColor>(button), Some(&primary));
+}
@@ -0,0 +1,27 @@
+#![cfg(feature = "plugin")]
+use bevy::prelude::*;
+use bevy::ecs::system::RunSystemOnce;
+use bevy_ui_dsl::*;
+
+mod common;
+
+fn spawn(assets: Res<AssetServer>, mut commands: Commands) {
+    root((), &assets, &mut commands, |p| {
+        virtual_list(1000, 20.0, (), p, |p, index| {
+            text(format!("Row {index}"), (), (), p);
+        });
+    });
+}
+
+#[test]
+fn content_is_sized_to_every_row() {
+    let mut app = common::app();
+    app.world_mut().run_system_once(spawn).unwrap();
+    app.update();
+    let content = app.world_mut().query::<&ScrollView>().single(app.world()).content;
+    assert_eq!(app.world().get::<Node>(content).unwrap().height, Val::Px(20000.0));
+
+    app.world_mut().query::<&mut VirtualList>().single_mut(app.world_mut()).set_item_count(10);
+    app.update();
+    assert_eq!(app.world().get::<Node>(content).unwrap().height, Val::Px(200.0));
+}